@@ -2,7 +2,10 @@ use std::fmt::Debug;
 
 use clap::{Parser, ValueEnum};
 use erased_serde::{serialize_trait_object, Serialize};
-use musicbrainz::{Area, Client, Entity, Mbid, Release};
+use musicbrainz::{
+    Area, Artist, Client, Entity, Event, Genre, Instrument, Label, Mbid, MusicBrainzError, Place,
+    Recording, Release, ReleaseGroup, Series, Url, Work,
+};
 use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
 
 trait ErasedEntity: Serialize + Debug {}
@@ -32,13 +35,23 @@ enum EntityType {
 impl EntityType {
     async fn lookup(
         &self,
-        client: &mut Client,
+        client: &Client,
         mbid: &Mbid,
-    ) -> anyhow::Result<Box<dyn ErasedEntity>> {
+    ) -> Result<Box<dyn ErasedEntity>, MusicBrainzError> {
         match self {
             EntityType::Area => Ok(Box::new(Area::lookup(client, mbid).await?)),
+            EntityType::Artist => Ok(Box::new(Artist::lookup(client, mbid).await?)),
+            EntityType::Event => Ok(Box::new(Event::lookup(client, mbid).await?)),
+            EntityType::Genre => Ok(Box::new(Genre::lookup(client, mbid).await?)),
+            EntityType::Instrument => Ok(Box::new(Instrument::lookup(client, mbid).await?)),
+            EntityType::Label => Ok(Box::new(Label::lookup(client, mbid).await?)),
+            EntityType::Place => Ok(Box::new(Place::lookup(client, mbid).await?)),
+            EntityType::Recording => Ok(Box::new(Recording::lookup(client, mbid).await?)),
             EntityType::Release => Ok(Box::new(Release::lookup(client, mbid).await?)),
-            _ => anyhow::bail!("Unimplemented entity {}", self),
+            EntityType::ReleaseGroup => Ok(Box::new(ReleaseGroup::lookup(client, mbid).await?)),
+            EntityType::Series => Ok(Box::new(Series::lookup(client, mbid).await?)),
+            EntityType::Url => Ok(Box::new(Url::lookup(client, mbid).await?)),
+            EntityType::Work => Ok(Box::new(Work::lookup(client, mbid).await?)),
         }
     }
 }
@@ -50,6 +63,9 @@ struct Args {
     json: bool,
     #[clap(short, long, value_enum)]
     entity: Option<EntityType>,
+    /// Fail on any field of the response our types don't know about.
+    #[clap(short, long)]
+    strict: bool,
     mbid: Mbid,
 }
 
@@ -61,17 +77,20 @@ async fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
 
-    let mut client = Client::new()?;
+    let client = Client::builder().strict(args.strict).build()?;
 
     let mut entities: Vec<Box<dyn ErasedEntity>> = Vec::new();
 
     if let Some(etype) = args.entity {
-        entities.push(etype.lookup(&mut client, &args.mbid).await?);
+        entities.push(etype.lookup(&client, &args.mbid).await?);
     } else {
         for etype in EntityType::iter() {
-            match etype.lookup(&mut client, &args.mbid).await {
+            match etype.lookup(&client, &args.mbid).await {
                 Ok(entity) => entities.push(entity),
-                Err(_) => tracing::warn!("no {} with MBID {}", etype, &args.mbid),
+                Err(MusicBrainzError::NotFound(_)) => {
+                    tracing::warn!("no {} with MBID {}", etype, &args.mbid)
+                }
+                Err(e) => return Err(e.into()),
             }
         }
         if entities.is_empty() {
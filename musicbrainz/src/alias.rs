@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Mbid, PartialDate};
+
+/// Aliases are alternate names for an entity, such as misspellings, names in other scripts or
+/// legal names. They are used to improve search results, and can be used to show an entity's
+/// name in the user's locale.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Aliases)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Alias {
+    pub name: String,
+    pub sort_name: String,
+    /// The type of alias, e.g. "Artist name", "Legal name" or "Search hint".
+    #[serde(rename = "type")]
+    pub a_type: Option<String>,
+    pub type_id: Option<Mbid>,
+    /// The locale this alias is the name of the entity in, e.g. `en` or `ja_JP`.
+    pub locale: Option<String>,
+    /// Whether this is the preferred alias for its locale.
+    pub primary: Option<bool>,
+    pub begin: Option<PartialDate>,
+    pub end: Option<PartialDate>,
+    pub ended: Option<bool>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Entity, Mbid};
+use crate::{vocabulary::vocabulary, BrowseFilter, Entity, Include, Mbid, Relationship};
 
 /// Areas are geographic regions or settlements.
 ///
@@ -19,21 +19,70 @@ pub struct Area {
     pub a_type: Option<AreaType>,
     pub type_id: Option<Mbid>,
     /// [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) codes for the area
+    #[serde(default)]
     pub iso_3166_1_codes: Vec<String>,
+    #[serde(default)]
     pub disambiguation: String,
+    /// Links to other entities and URLs, see the `*Rels` variants of [`AreaInc`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
-pub enum AreaType {
-    Country,
-    Subdivision,
-    County,
-    Municipality,
-    City,
-    District,
-    Island
+vocabulary! {
+    pub enum AreaType {
+        Country = "Country",
+        Subdivision = "Subdivision",
+        County = "County",
+        Municipality = "Municipality",
+        City = "City",
+        District = "District",
+        Island = "Island",
+    }
+}
+
+/// The entities [`Area`]s can be browsed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AreaBrowseBy {
+    Collection(Mbid),
+}
+
+impl BrowseFilter for AreaBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match self {
+            AreaBrowseBy::Collection(mbid) => ("collection", mbid),
+        }
+    }
+}
+
+/// The subqueries that can be included in an [`Area`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AreaInc {
+    Aliases,
+    Annotation,
+    Tags,
+    Genres,
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
 }
 
+impl Include for AreaInc {}
+
 impl Entity for Area {
     const NAME: &'static str = "area";
+    const PLURAL: &'static str = "areas";
+    type BrowseBy = AreaBrowseBy;
+    type Include = AreaInc;
 }
@@ -0,0 +1,259 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alias::Alias,
+    index::{Document, Indexed},
+    life_span::LifeSpan,
+    vocabulary::vocabulary,
+    Area, BrowseFilter, Entity, Include, Mbid, Recording, Relationship, Release, ReleaseGroup,
+};
+
+/// An artist is generally a musician (or musician persona), group of musicians, or other music
+/// professional (like a producer or engineer). Occasionally, it can also be a non-musical person
+/// (like a photographer, an illustrator, or a poet whose writings are set to music), or even a
+/// fictional character.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Artist)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Artist {
+    /// The official name of the artist.
+    pub name: String,
+    /// The name used to sort the artist, e.g. "Beatles, The".
+    pub sort_name: String,
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    /// Whether the artist is a person, a group, etc.
+    #[serde(rename = "type")]
+    pub a_type: Option<ArtistType>,
+    pub type_id: Option<Mbid>,
+    /// The gender of a person or character.
+    pub gender: Option<Gender>,
+    pub gender_id: Option<Mbid>,
+    /// The [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code of the
+    /// country the artist is primarily identified with.
+    pub country: Option<String>,
+    /// The area the artist is primarily identified with.
+    pub area: Option<Area>,
+    /// Where a person was born, or where a group was founded.
+    pub begin_area: Option<Area>,
+    /// Where a person died, or where a group was dissolved.
+    pub end_area: Option<Area>,
+    /// When a person was born and died, or when a group was founded and dissolved.
+    pub life_span: Option<LifeSpan>,
+    #[serde(default)]
+    pub disambiguation: String,
+    /// [Interested Parties Information](https://musicbrainz.org/doc/IPI) codes.
+    #[serde(default)]
+    pub ipis: Vec<String>,
+    /// [International Standard Name Identifiers](https://musicbrainz.org/doc/ISNI).
+    #[serde(default)]
+    pub isnis: Vec<String>,
+    /// See [`ArtistInc::Aliases`].
+    pub aliases: Option<Vec<Alias>>,
+    /// See [`ArtistInc::Recordings`].
+    pub recordings: Option<Vec<Recording>>,
+    /// See [`ArtistInc::Releases`].
+    pub releases: Option<Vec<Release>>,
+    /// See [`ArtistInc::ReleaseGroups`].
+    pub release_groups: Option<Vec<ReleaseGroup>>,
+    /// Links to other entities and URLs, see the `*Rels` variants of [`ArtistInc`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+vocabulary! {
+    pub enum ArtistType {
+        /// An individual person, whether they are credited under their legal name or a stage name.
+        Person = "Person",
+        /// A group of people that may or may not have a distinctive name.
+        Group = "Group",
+        /// A large instrumental ensemble.
+        Orchestra = "Orchestra",
+        /// A group of singers.
+        Choir = "Choir",
+        /// A fictional character, such as a cartoon or game character credited for a release.
+        Character = "Character",
+        /// Anything which does not fit into the above categories.
+        Other = "Other",
+    }
+}
+
+vocabulary! {
+    pub enum Gender {
+        Male = "Male",
+        Female = "Female",
+        NonBinary = "Non-binary",
+        Other = "Other",
+        /// Used for characters which lack a gender, such as robots.
+        NotApplicable = "Not applicable",
+    }
+}
+
+/// The entities [`Artist`]s can be browsed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtistBrowseBy {
+    Area(Mbid),
+    Collection(Mbid),
+    Recording(Mbid),
+    Release(Mbid),
+    ReleaseGroup(Mbid),
+    Work(Mbid),
+}
+
+impl BrowseFilter for ArtistBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match self {
+            ArtistBrowseBy::Area(mbid) => ("area", mbid),
+            ArtistBrowseBy::Collection(mbid) => ("collection", mbid),
+            ArtistBrowseBy::Recording(mbid) => ("recording", mbid),
+            ArtistBrowseBy::Release(mbid) => ("release", mbid),
+            ArtistBrowseBy::ReleaseGroup(mbid) => ("release-group", mbid),
+            ArtistBrowseBy::Work(mbid) => ("work", mbid),
+        }
+    }
+}
+
+/// The subqueries that can be included in an [`Artist`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ArtistInc {
+    /// The recordings credited to the artist.
+    Recordings,
+    /// The releases credited to the artist.
+    Releases,
+    /// The release groups credited to the artist.
+    ReleaseGroups,
+    Works,
+    /// Restricts `Releases` and `ReleaseGroups` to various artists releases featuring the artist.
+    VariousArtists,
+    Aliases,
+    Annotation,
+    Tags,
+    Genres,
+    Ratings,
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
+}
+
+impl Include for ArtistInc {}
+
+impl Entity for Artist {
+    const NAME: &'static str = "artist";
+    const PLURAL: &'static str = "artists";
+    type BrowseBy = ArtistBrowseBy;
+    type Include = ArtistInc;
+}
+
+impl Indexed for Artist {
+    const DEFAULT_FIELD: &'static str = "artist";
+
+    fn mbid(&self) -> &Mbid {
+        &self.id
+    }
+
+    fn index(&self, doc: &mut Document) {
+        doc.text("artist", &self.name);
+        doc.text("sortname", &self.sort_name);
+        doc.aliases(self.aliases.as_deref());
+        doc.keyword("country", self.country.as_deref().unwrap_or_default());
+        if let Some(a_type) = &self.a_type {
+            doc.keyword("type", a_type.as_str());
+        }
+        doc.keyword("arid", &self.id.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::mock;
+
+    #[tokio::test]
+    async fn lookup_artist() {
+        let server = MockServer::start().await;
+        mock::mount_lookup(
+            &server,
+            "artist/66fc5bf8-daa4-4241-b378-9bc9077939d2",
+            "aliases",
+            r#"{
+                "id": "66fc5bf8-daa4-4241-b378-9bc9077939d2",
+                "name": "Tool",
+                "sort-name": "Tool",
+                "type": "Group",
+                "type-id": "e431f5f6-b5d2-343d-8b36-72607fffb74b",
+                "gender": null,
+                "gender-id": null,
+                "country": "US",
+                "area": {
+                    "id": "489ce91b-6658-3307-9877-795b68554c98",
+                    "name": "United States",
+                    "sort-name": "United States",
+                    "type": null,
+                    "type-id": null,
+                    "iso-3166-1-codes": ["US"],
+                    "disambiguation": ""
+                },
+                "begin-area": {
+                    "id": "1f40c6e1-47ba-4e35-996f-fe6ee5840e62",
+                    "name": "Los Angeles",
+                    "sort-name": "Los Angeles",
+                    "type": null,
+                    "type-id": null,
+                    "disambiguation": ""
+                },
+                "end-area": null,
+                "life-span": {"begin": "1990", "end": null, "ended": false},
+                "disambiguation": "US rock band",
+                "ipis": [],
+                "isnis": ["0000000115017862"],
+                "aliases": [{
+                    "name": "TOOL",
+                    "sort-name": "TOOL",
+                    "type": "Search hint",
+                    "type-id": "1937e404-b981-3cb7-8151-4c86ebfc8d8e",
+                    "locale": null,
+                    "primary": null,
+                    "begin": null,
+                    "end": null,
+                    "ended": false
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let artist: Artist = client
+            .lookup_with(
+                &"66fc5bf8-daa4-4241-b378-9bc9077939d2".parse().unwrap(),
+                &[ArtistInc::Aliases],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(artist.a_type, Some(ArtistType::Group));
+        assert_eq!(artist.begin_area.unwrap().name, "Los Angeles");
+        assert_eq!(
+            artist.life_span.unwrap().begin.map(|d| d.year),
+            Some(Some(1990))
+        );
+        assert_eq!(artist.isnis, ["0000000115017862"]);
+        assert_eq!(artist.aliases.unwrap()[0].name, "TOOL");
+        assert!(artist.releases.is_none());
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Artist, Mbid};
+
+/// The artists credited for a release, recording, etc., as they appear on it.
+///
+/// The [`Display`](fmt::Display) implementation renders the full credit, e.g. "A feat. B & C".
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Artist_Credits)
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct ArtistCredit(pub Vec<CreditedArtist>);
+
+/// A single artist in an [`ArtistCredit`].
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CreditedArtist {
+    /// The name the artist is credited as, which may differ from the artist's name.
+    pub name: String,
+    /// The text joining this artist's name to the next one, e.g. `" feat. "`.
+    #[serde(default)]
+    pub joinphrase: String,
+    pub artist: Artist,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ArtistCredit {
+    /// The names the artists are credited as, in order.
+    pub fn names(&self) -> Vec<&str> {
+        self.0.iter().map(|credit| credit.name.as_str()).collect()
+    }
+
+    /// The MBIDs of the credited artists, in order and without duplicates.
+    pub fn artist_ids(&self) -> Vec<&Mbid> {
+        let mut ids: Vec<&Mbid> = Vec::with_capacity(self.0.len());
+        for credit in &self.0 {
+            if !ids.contains(&&credit.artist.id) {
+                ids.push(&credit.artist.id);
+            }
+        }
+        ids
+    }
+}
+
+impl fmt::Display for ArtistCredit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for credit in &self.0 {
+            write!(f, "{}{}", credit.name, credit.joinphrase)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credit(json: &str) -> ArtistCredit {
+        serde_json::from_str(json).unwrap()
+    }
+
+    const FEATURING: &str = r#"[
+        {
+            "name": "Jay-Z",
+            "joinphrase": " feat. ",
+            "artist": {"id": "f82bcf78-5b69-4622-a5ef-73800768d9ac", "name": "JAY‐Z", "sort-name": "JAY‐Z"}
+        },
+        {
+            "name": "Linkin Park",
+            "joinphrase": " & ",
+            "artist": {"id": "f59c5520-5f46-4d2c-b2c4-822eabf53419", "name": "Linkin Park", "sort-name": "Linkin Park"}
+        },
+        {
+            "name": "Hova",
+            "joinphrase": "",
+            "artist": {"id": "f82bcf78-5b69-4622-a5ef-73800768d9ac", "name": "JAY‐Z", "sort-name": "JAY‐Z"}
+        }
+    ]"#;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            credit(FEATURING).to_string(),
+            "Jay-Z feat. Linkin Park & Hova"
+        );
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(credit(FEATURING).names(), ["Jay-Z", "Linkin Park", "Hova"]);
+    }
+
+    #[test]
+    fn artist_ids() {
+        let credit = credit(FEATURING);
+        let ids: Vec<String> = credit
+            .artist_ids()
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                "f82bcf78-5b69-4622-a5ef-73800768d9ac",
+                "f59c5520-5f46-4d2c-b2c4-822eabf53419"
+            ]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let credit = ArtistCredit::default();
+        assert_eq!(credit.to_string(), "");
+        assert!(credit.artist_ids().is_empty());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{Client, Entity, Mbid, MusicBrainzError};
+
+/// A source of MusicBrainz entities, such as the web service through a [`Client`] or a local
+/// data dump through a [`DumpBackend`](crate::dump::DumpBackend).
+///
+/// Code which only needs to look entities up can be written against this trait, and so work with
+/// either.
+#[async_trait::async_trait]
+pub trait Backend: Send + Sync {
+    /// Looks up an entity along with the related data selected by `includes`.
+    ///
+    /// Backends which always return every related data they have, such as dumps, ignore
+    /// `includes`.
+    async fn lookup_with<E: Entity>(
+        &self,
+        mbid: &Mbid,
+        includes: &[E::Include],
+    ) -> Result<E, MusicBrainzError>;
+
+    async fn lookup<E: Entity>(&self, mbid: &Mbid) -> Result<E, MusicBrainzError> {
+        self.lookup_with::<E>(mbid, &[]).await
+    }
+}
+
+#[async_trait::async_trait]
+impl Backend for Client {
+    async fn lookup_with<E: Entity>(
+        &self,
+        mbid: &Mbid,
+        includes: &[E::Include],
+    ) -> Result<E, MusicBrainzError> {
+        E::lookup_with(self, mbid, includes).await
+    }
+}
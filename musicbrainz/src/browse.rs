@@ -0,0 +1,16 @@
+use std::fmt::Debug;
+
+use crate::Mbid;
+
+/// Restricts a browse request to the entities linked to another one, e.g. all the releases in a
+/// release group.
+///
+/// Every [`Entity`](crate::Entity) has its own set of filters, so that only the links MusicBrainz
+/// supports for it can be expressed.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/MusicBrainz_API#Browse)
+pub trait BrowseFilter: Clone + Debug + Send + Sync + 'static {
+    /// The name of the linked entity, as used in the query string, and its MBID.
+    fn linked(&self) -> (&'static str, &Mbid);
+}
@@ -0,0 +1,388 @@
+use std::{
+    fmt::Write as _,
+    future::Future,
+    path::PathBuf,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::{
+    header::{CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    Request, Response, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncWriteExt;
+use tower::{buffer::Buffer, BoxError, Layer, Service, ServiceExt};
+
+use crate::retry::Permanent;
+
+/// A persistent cache of MusicBrainz responses, stored as one file per URL in a directory.
+///
+/// Responses are served from the cache for as long as they are younger than the
+/// [`ttl`](Self::ttl). Once they are older, MusicBrainz is asked whether they changed with a
+/// conditional request, which doesn't transfer the body again when they haven't.
+///
+/// In [`offline`](Self::offline) mode the network is never used: every cached response is served
+/// regardless of its age, and anything else fails with [`NotCached`].
+///
+/// Cache hits skip the rate limit, so it's best added with
+/// [`ClientBuilder::cache`](crate::ClientBuilder::cache).
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+    ttl: Duration,
+    offline: bool,
+    buffer: usize,
+}
+
+/// The error returned for requests which aren't in the cache in offline mode.
+#[derive(Debug, thiserror::Error)]
+#[error("{0} is not cached")]
+pub struct NotCached(pub reqwest::Url);
+
+/// What is stored for every cached response.
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    url: String,
+    /// When the response was last fetched or revalidated, in seconds since the Unix epoch.
+    fetched: u64,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: String,
+}
+
+impl Cache {
+    /// A cache stored in `dir`, which is created if needed, with a TTL of one day.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl: Duration::from_secs(24 * 60 * 60),
+            offline: false,
+            buffer: 100,
+        }
+    }
+
+    /// How long responses are served without asking MusicBrainz whether they changed.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Whether to only ever serve responses from the cache.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// How many requests can wait for the inner service at once, see
+    /// [`ClientBuilder::buffer`](crate::ClientBuilder::buffer). Defaults to 100.
+    pub fn buffer(mut self, buffer: usize) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    fn path(&self, url: &reqwest::Url) -> PathBuf {
+        let hash = Sha256::digest(url.as_str().as_bytes());
+        let mut name = String::with_capacity(hash.len() * 2 + 5);
+        for byte in hash {
+            let _ = write!(name, "{:02x}", byte);
+        }
+        name.push_str(".json");
+        self.dir.join(name)
+    }
+
+    async fn read(&self, url: &reqwest::Url) -> Option<Entry> {
+        let text = match tokio::fs::read_to_string(self.path(url)).await {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+            Err(e) => {
+                tracing::warn!(%url, error = %e, "Failed to read cache entry");
+                return None;
+            }
+        };
+        match serde_json::from_str::<Entry>(&text) {
+            // Guard against hash collisions, however unlikely
+            Ok(entry) if entry.url == url.as_str() => Some(entry),
+            Ok(_) => None,
+            Err(e) => {
+                tracing::warn!(%url, error = %e, "Ignoring corrupt cache entry");
+                None
+            }
+        }
+    }
+
+    async fn write(&self, url: &reqwest::Url, entry: &Entry) {
+        let path = self.path(url);
+        // Written to a temporary file first, so that readers never see a partial entry. Its name
+        // is unique, so that concurrent writes of the same entry don't interleave.
+        let tmp = path.with_extension(format!("json.{:016x}.tmp", fastrand::u64(..)));
+        let result = async {
+            tokio::fs::create_dir_all(&self.dir).await?;
+            let mut file = tokio::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&tmp)
+                .await?;
+            file.write_all(&serde_json::to_vec(entry)?).await?;
+            file.flush().await?;
+            drop(file);
+            tokio::fs::rename(&tmp, &path).await
+        };
+        if let Err(e) = result.await {
+            tracing::warn!(%url, error = %e, "Failed to write cache entry");
+            let _ = tokio::fs::remove_file(&tmp).await;
+        }
+    }
+
+    fn is_fresh(&self, entry: &Entry) -> bool {
+        Duration::from_secs(now().saturating_sub(entry.fetched)) < self.ttl
+    }
+}
+
+impl<S> Layer<S> for Cache
+where
+    S: Service<Request, Response = Response, Error = BoxError> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Service = CacheService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CacheService {
+            cache: self.clone(),
+            // Buffered so that requests only wait for the inner service, e.g. the rate limit, when
+            // they actually miss the cache
+            inner: Buffer::new(inner, self.buffer),
+        }
+    }
+}
+
+/// The service created by the [`Cache`] layer.
+pub struct CacheService<S>
+where
+    S: Service<Request>,
+{
+    cache: Cache,
+    inner: Buffer<S, Request>,
+}
+
+impl<S> Service<Request> for CacheService<S>
+where
+    S: Service<Request, Response = Response, Error = BoxError> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, mut req: Request) -> Self::Future {
+        let cache = self.cache.clone();
+        let inner = self.inner.clone();
+        Box::pin(async move {
+            let url = req.url().clone();
+            let entry = cache.read(&url).await;
+
+            match entry {
+                Some(entry) if cache.offline || cache.is_fresh(&entry) => {
+                    tracing::debug!(%url, "Cache hit");
+                    return Ok(response(entry.body));
+                }
+                None if cache.offline => return Err(Permanent::new(NotCached(url)).into()),
+                Some(ref entry) => {
+                    tracing::debug!(%url, "Revalidating stale cache entry");
+                    let headers = req.headers_mut();
+                    if let Some(etag) = entry.etag.as_deref().and_then(|v| v.parse().ok()) {
+                        headers.insert(IF_NONE_MATCH, etag);
+                    }
+                    if let Some(date) = entry.last_modified.as_deref().and_then(|v| v.parse().ok())
+                    {
+                        headers.insert(IF_MODIFIED_SINCE, date);
+                    }
+                }
+                None => tracing::debug!(%url, "Cache miss"),
+            }
+
+            let res = inner.oneshot(req).await?;
+            let header = |name| {
+                res.headers()
+                    .get(name)
+                    .and_then(|v: &reqwest::header::HeaderValue| v.to_str().ok())
+                    .map(str::to_string)
+            };
+            match (res.status(), entry) {
+                (StatusCode::NOT_MODIFIED, Some(entry)) => {
+                    let entry = Entry {
+                        fetched: now(),
+                        etag: header(ETAG).or(entry.etag),
+                        last_modified: header(LAST_MODIFIED).or(entry.last_modified),
+                        ..entry
+                    };
+                    cache.write(&url, &entry).await;
+                    Ok(response(entry.body))
+                }
+                (StatusCode::OK, _) => {
+                    let etag = header(ETAG);
+                    let last_modified = header(LAST_MODIFIED);
+                    let body = res.text().await?;
+                    let entry = Entry {
+                        url: url.to_string(),
+                        fetched: now(),
+                        etag,
+                        last_modified,
+                        body,
+                    };
+                    cache.write(&url, &entry).await;
+                    Ok(response(entry.body))
+                }
+                _ => Ok(res),
+            }
+        })
+    }
+}
+
+fn response(body: String) -> Response {
+    http::Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/json")
+        .body(body)
+        .expect("valid cached response")
+        .into()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::{mock, Client, Genre, Mbid, MusicBrainzError};
+
+    const GENRE: &str =
+        r#"{"id": "ceeaa283-5d7b-4202-8d1d-e25d116b2a18", "name": "alternative rock"}"#;
+
+    fn cached_client(server: &MockServer, cache: Cache) -> Client {
+        Client::builder()
+            .base_url(reqwest::Url::parse(&server.uri()).unwrap())
+            .rate_limit(None)
+            .cache(cache)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn hit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(GENRE, "application/json"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let client = cached_client(&server, Cache::new(dir.path()));
+        let mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+        for _ in 0..3 {
+            let genre: Genre = client.lookup(&mbid).await.unwrap();
+            assert_eq!(genre.name, "alternative rock");
+        }
+
+        // The cache outlives the client
+        let client = cached_client(&server, Cache::new(dir.path()).offline(true));
+        let genre: Genre = client.lookup(&mbid).await.unwrap();
+        assert_eq!(genre.name, "alternative rock");
+    }
+
+    #[tokio::test]
+    async fn concurrent_writes() {
+        let server = MockServer::start().await;
+        mock::mount_json(&server, "genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18", GENRE).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let client = cached_client(&server, Cache::new(dir.path()));
+        let mbid: Mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.lookup::<Genre>(&mbid).await })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap().unwrap().name, "alternative rock");
+        }
+
+        // Every write left a complete entry behind, and no temporary files
+        let files: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(files.len(), 1);
+        let client = cached_client(&server, Cache::new(dir.path()).offline(true));
+        let genre: Genre = client.lookup(&mbid).await.unwrap();
+        assert_eq!(genre.name, "alternative rock");
+    }
+
+    #[tokio::test]
+    async fn revalidation() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_raw(GENRE, "application/json"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let client = cached_client(&server, Cache::new(dir.path()).ttl(Duration::ZERO));
+        let mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+        for _ in 0..3 {
+            let genre: Genre = client.lookup(&mbid).await.unwrap();
+            assert_eq!(genre.name, "alternative rock");
+        }
+    }
+
+    #[tokio::test]
+    async fn offline_miss() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(GENRE, "application/json"))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let client = cached_client(&server, Cache::new(dir.path()).offline(true));
+        let mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+        let err = client.lookup::<Genre>(&mbid).await.unwrap_err();
+        match err {
+            MusicBrainzError::NotCached(url) => assert_eq!(
+                url.path(),
+                "/ws/2/genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18"
+            ),
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Serialize};
+
+/// A date which may be only partially known, as is common in MusicBrainz, e.g. a release whose
+/// year of release is known but not its month or day.
+///
+/// Dates are written as `YYYY-MM-DD`, `YYYY-MM`, `YYYY` or an empty string, with unknown
+/// components in the middle written as `??`, e.g. `????-05-12`.
+///
+/// Dates are ordered component by component, with unknown components sorting first, so `2001`
+/// comes before `2001-05`, which comes before `2001-05-15`.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Style/Release#Date)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PartialDate {
+    pub year: Option<u16>,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid partial date `{0}`")]
+pub struct ParsePartialDateError(String);
+
+impl PartialDate {
+    /// Whether no component of the date is known.
+    pub fn is_empty(&self) -> bool {
+        self.year.is_none() && self.month.is_none() && self.day.is_none()
+    }
+
+    /// Converts to a [`time::Date`], if every component is known and valid.
+    pub fn to_date(&self) -> Option<time::Date> {
+        let month = time::Month::try_from(self.month?).ok()?;
+        time::Date::from_calendar_date(self.year?.into(), month, self.day?).ok()
+    }
+}
+
+impl From<time::Date> for PartialDate {
+    fn from(date: time::Date) -> Self {
+        Self {
+            year: u16::try_from(date.year()).ok(),
+            month: Some(date.month().into()),
+            day: Some(date.day()),
+        }
+    }
+}
+
+impl FromStr for PartialDate {
+    type Err = ParsePartialDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParsePartialDateError(s.to_string());
+
+        fn component<T: FromStr>(part: Option<&str>, len: usize) -> Result<Option<T>, ()> {
+            match part {
+                None => Ok(None),
+                Some(part) if part.len() != len => Err(()),
+                Some(part) if part.bytes().all(|b| b == b'?') => Ok(None),
+                Some(part) if part.bytes().all(|b| b.is_ascii_digit()) => {
+                    part.parse().map(Some).map_err(|_| ())
+                }
+                Some(_) => Err(()),
+            }
+        }
+
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let mut parts = s.split('-');
+        let date = Self {
+            year: component(parts.next(), 4).map_err(|_| err())?,
+            month: component(parts.next(), 2).map_err(|_| err())?,
+            day: component(parts.next(), 2).map_err(|_| err())?,
+        };
+        if parts.next().is_some()
+            || matches!(date.month, Some(m) if !(1..=12).contains(&m))
+            || matches!(date.day, Some(d) if !(1..=31).contains(&d))
+        {
+            return Err(err());
+        }
+        Ok(date)
+    }
+}
+
+impl fmt::Display for PartialDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = match (self.year, self.month, self.day) {
+            (_, _, Some(_)) => 3,
+            (_, Some(_), None) => 2,
+            (Some(_), None, None) => 1,
+            (None, None, None) => 0,
+        };
+        if len >= 1 {
+            match self.year {
+                Some(year) => write!(f, "{:04}", year)?,
+                None => f.write_str("????")?,
+            }
+        }
+        for component in [self.month, self.day].iter().take(len.max(1) - 1) {
+            match component {
+                Some(component) => write!(f, "-{:02}", component)?,
+                None => f.write_str("-??")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for PartialDate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PartialDate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn date(year: Option<u16>, month: Option<u8>, day: Option<u8>) -> PartialDate {
+        PartialDate { year, month, day }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("".parse::<PartialDate>().unwrap(), PartialDate::default());
+        assert_eq!(
+            "2001".parse::<PartialDate>().unwrap(),
+            date(Some(2001), None, None)
+        );
+        assert_eq!(
+            "2001-05".parse::<PartialDate>().unwrap(),
+            date(Some(2001), Some(5), None)
+        );
+        assert_eq!(
+            "2001-05-15".parse::<PartialDate>().unwrap(),
+            date(Some(2001), Some(5), Some(15))
+        );
+        assert_eq!(
+            "????-05-15".parse::<PartialDate>().unwrap(),
+            date(None, Some(5), Some(15))
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in [
+            "01",
+            "2001-5",
+            "2001-13",
+            "2001-05-32",
+            "2001-05-15-01",
+            "May 2001",
+        ] {
+            assert!(s.parse::<PartialDate>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let mut dates: Vec<PartialDate> = ["2001-05-15", "", "2001", "1999-12-31", "2001-05"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        dates.sort();
+        let dates: Vec<String> = dates.iter().map(ToString::to_string).collect();
+        assert_eq!(dates, ["", "1999-12-31", "2001", "2001-05", "2001-05-15"]);
+    }
+
+    #[test]
+    fn to_date() {
+        let complete: PartialDate = "2001-05-15".parse().unwrap();
+        let expected = time::Date::from_calendar_date(2001, time::Month::May, 15).unwrap();
+        assert_eq!(complete.to_date(), Some(expected));
+        assert_eq!(PartialDate::from(expected), complete);
+        let partial: PartialDate = "2001-05".parse().unwrap();
+        assert_eq!(partial.to_date(), None);
+        let invalid: PartialDate = "2001-02-31".parse().unwrap();
+        assert_eq!(invalid.to_date(), None);
+    }
+
+    #[test]
+    fn serde() {
+        let dates: Vec<Option<PartialDate>> =
+            serde_json::from_str(r#"["2001-05", "", null]"#).unwrap();
+        assert_eq!(
+            dates,
+            [
+                Some(date(Some(2001), Some(5), None)),
+                Some(PartialDate::default()),
+                None
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&dates).unwrap(),
+            r#"["2001-05","",null]"#
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn to_and_from_string(
+            year in proptest::option::of(0..=9999u16),
+            month in proptest::option::of(1..=12u8),
+            day in proptest::option::of(1..=31u8),
+        ) {
+            let date = date(year, month, day);
+            let s = date.to_string();
+            assert_eq!(s.parse::<PartialDate>().unwrap(), date);
+        }
+    }
+}
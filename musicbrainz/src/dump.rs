@@ -0,0 +1,243 @@
+use std::{
+    collections::HashMap,
+    io::SeekFrom,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use futures::{stream, Stream};
+use serde::Deserialize;
+use tokio::{
+    fs::File,
+    io::{AsyncBufReadExt, AsyncSeekExt, BufReader},
+    sync::OnceCell,
+};
+
+use crate::{backend::Backend, strict, Entity, Mbid, MusicBrainzError};
+
+/// Reads entities from the [JSON data dumps](https://musicbrainz.org/doc/MusicBrainz_Database/Download)
+/// MusicBrainz publishes, without touching the network.
+///
+/// The dumps are expected to be extracted into a single directory, with one file per entity
+/// named after it, e.g. `release` or `release-group`. Each line of these holds one entity, in the
+/// same shape the web service returns it with every include.
+///
+/// The first lookup of an entity type scans its whole file to index the offset of every MBID, so
+/// later lookups only read a single line.
+#[derive(Debug)]
+pub struct DumpBackend {
+    dir: PathBuf,
+    strict: bool,
+    /// The index of every entity type, built by the first lookup of that type while lookups of
+    /// others carry on.
+    indexes: Mutex<HashMap<&'static str, Arc<OnceCell<Arc<Index>>>>>,
+}
+
+/// The offset of the line holding every entity in a dump file.
+type Index = HashMap<Mbid, u64>;
+
+impl DumpBackend {
+    /// A backend reading the dump files in `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            strict: false,
+            indexes: Mutex::default(),
+        }
+    }
+
+    /// Whether to fail on entities which don't exactly match our types, as with
+    /// [`ClientBuilder::strict`](crate::ClientBuilder::strict).
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// The path of the dump file for `E`.
+    pub fn path<E: Entity>(&self) -> PathBuf {
+        self.dir.join(E::NAME)
+    }
+
+    /// Streams every entity in the dump file for `E`, in the order they appear in it.
+    pub async fn entities<E: Entity>(
+        &self,
+    ) -> Result<impl Stream<Item = Result<E, MusicBrainzError>>, MusicBrainzError> {
+        let lines = BufReader::new(open(&self.path::<E>()).await?).lines();
+        let strict = self.strict;
+        Ok(stream::try_unfold(lines, move |mut lines| async move {
+            loop {
+                match lines.next_line().await.map_err(MusicBrainzError::DumpIo)? {
+                    Some(line) if line.trim().is_empty() => continue,
+                    Some(line) => {
+                        let entity = parse(&line, strict)?;
+                        return Ok(Some((entity, lines)));
+                    }
+                    None => return Ok(None),
+                }
+            }
+        }))
+    }
+
+    /// Returns the index of the dump file for `E`, building it if this is the first time it's
+    /// needed.
+    async fn index<E: Entity>(&self) -> Result<Arc<Index>, MusicBrainzError> {
+        let cell = self
+            .indexes
+            .lock()
+            .expect("dump indexes poisoned")
+            .entry(E::NAME)
+            .or_default()
+            .clone();
+        let index = cell
+            .get_or_try_init(|| async { build_index(&self.path::<E>()).await.map(Arc::new) })
+            .await?;
+        Ok(index.clone())
+    }
+}
+
+/// Scans a dump file for the offset of every entity in it.
+async fn build_index(path: &Path) -> Result<Index, MusicBrainzError> {
+    #[derive(Deserialize)]
+    struct Id {
+        id: Mbid,
+    }
+
+    tracing::debug!(path = %path.display(), "Indexing dump");
+    let mut reader = BufReader::new(open(path).await?);
+    let mut index = HashMap::new();
+    let mut offset = 0;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        let read = reader
+            .read_line(&mut line)
+            .await
+            .map_err(MusicBrainzError::DumpIo)?;
+        if read == 0 {
+            break;
+        }
+        if !line.trim().is_empty() {
+            let Id { id } = serde_json::from_str(&line).map_err(MusicBrainzError::DumpParse)?;
+            index.insert(id, offset);
+        }
+        offset += read as u64;
+    }
+    tracing::debug!(path = %path.display(), entities = index.len(), "Indexed dump");
+    Ok(index)
+}
+
+#[async_trait::async_trait]
+impl Backend for DumpBackend {
+    async fn lookup_with<E: Entity>(
+        &self,
+        mbid: &Mbid,
+        _: &[E::Include],
+    ) -> Result<E, MusicBrainzError> {
+        let offset = *self
+            .index::<E>()
+            .await?
+            .get(mbid)
+            .ok_or(MusicBrainzError::NotFound(*mbid))?;
+
+        let mut file = open(&self.path::<E>()).await?;
+        file.seek(SeekFrom::Start(offset))
+            .await
+            .map_err(MusicBrainzError::DumpIo)?;
+        let mut line = String::new();
+        BufReader::new(file)
+            .read_line(&mut line)
+            .await
+            .map_err(MusicBrainzError::DumpIo)?;
+        parse(&line, self.strict)
+    }
+}
+
+fn parse<E: Entity>(line: &str, strict: bool) -> Result<E, MusicBrainzError> {
+    if strict {
+        strict::from_str(line).map_err(MusicBrainzError::Strict)
+    } else {
+        serde_json::from_str(line).map_err(MusicBrainzError::DumpParse)
+    }
+}
+
+async fn open(path: &Path) -> Result<File, MusicBrainzError> {
+    File::open(path).await.map_err(MusicBrainzError::DumpIo)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::{Artist, Genre, ReleaseGroup};
+
+    fn backend() -> DumpBackend {
+        DumpBackend::new(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/fixtures/dump"))
+    }
+
+    #[tokio::test]
+    async fn lookup() {
+        let backend = backend();
+        let tool: Artist = backend
+            .lookup(&"66fc5bf8-daa4-4241-b378-9bc9077939d2".parse().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(tool.name, "Tool");
+        assert_eq!(tool.aliases.unwrap().len(), 1);
+
+        let apc: Artist = backend
+            .lookup(&"f8ea3cf4-b2ba-4a4d-b4b9-0fab7a6a7e6a".parse().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(apc.name, "A Perfect Circle");
+
+        let lateralus: ReleaseGroup = backend
+            .lookup(&"7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93".parse().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(lateralus.title, "Lateralus");
+        assert_eq!(lateralus.artist_credit.unwrap().to_string(), "Tool");
+    }
+
+    #[tokio::test]
+    async fn concurrent_lookups() {
+        let backend = backend();
+        let tool = "66fc5bf8-daa4-4241-b378-9bc9077939d2".parse().unwrap();
+        let lateralus = "7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93".parse().unwrap();
+        // Both indexes are built at once, and the artist one only once
+        let (tool, again, lateralus) = futures::join!(
+            backend.lookup::<Artist>(&tool),
+            backend.lookup::<Artist>(&tool),
+            backend.lookup::<ReleaseGroup>(&lateralus),
+        );
+        assert_eq!(tool.unwrap(), again.unwrap());
+        assert_eq!(lateralus.unwrap().title, "Lateralus");
+    }
+
+    #[tokio::test]
+    async fn lookup_missing() {
+        let backend = backend();
+        let mbid = "00000000-0000-0000-0000-000000000000".parse().unwrap();
+        let err = backend.lookup::<Artist>(&mbid).await.unwrap_err();
+        assert!(
+            matches!(err, MusicBrainzError::NotFound(m) if m == mbid),
+            "{:?}",
+            err
+        );
+        let err = backend.lookup::<Genre>(&mbid).await.unwrap_err();
+        assert!(matches!(err, MusicBrainzError::DumpIo(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn entities() {
+        let names: Vec<String> = backend()
+            .entities::<Artist>()
+            .await
+            .unwrap()
+            .map_ok(|artist| artist.name)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(names, ["Tool", "A Perfect Circle"]);
+    }
+}
@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alias::Alias, life_span::LifeSpan, vocabulary::vocabulary, BrowseFilter, Entity, Include, Mbid,
+    Relationship,
+};
+
+/// An event refers to an organised event which people can attend, and is relevant to
+/// MusicBrainz. Generally this means live performances, like concerts and festivals.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Event)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Event {
+    /// The name of the event, if it has one, e.g. "Glastonbury 2019".
+    pub name: String,
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    #[serde(rename = "type")]
+    pub a_type: Option<EventType>,
+    pub type_id: Option<Mbid>,
+    /// The start time of the event, e.g. `20:00`.
+    pub time: Option<String>,
+    /// The setlist of the event, in MusicBrainz'
+    /// [setlist syntax](https://musicbrainz.org/doc/Event/Setlist).
+    pub setlist: Option<String>,
+    /// Whether the event was cancelled.
+    #[serde(default)]
+    pub cancelled: bool,
+    /// When the event started and ended.
+    pub life_span: Option<LifeSpan>,
+    #[serde(default)]
+    pub disambiguation: String,
+    /// See [`EventInc::Aliases`].
+    pub aliases: Option<Vec<Alias>>,
+    /// Links to other entities and URLs, see the `*Rels` variants of [`EventInc`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+vocabulary! {
+    pub enum EventType {
+        /// An individual concert by a single artist or collaboration, often with supporting artists
+        /// who perform before the main act.
+        Concert = "Concert",
+        /// An event where a number of different acts perform across the course of the day.
+        Festival = "Festival",
+        /// A performance of one or more plays, musicals, operas, ballets or other similar works.
+        StagePerformance = "Stage performance",
+        /// An event where awards are given, often including performances.
+        AwardCeremony = "Award ceremony",
+        /// A party, reception or other event held specifically for the launch of a release.
+        LaunchEvent = "Launch event",
+        /// A convention, expo or trade fair, generally with performances.
+        ConventionExpo = "Convention/Expo",
+        /// A masterclass or clinic, where an artist teaches.
+        MasterclassClinic = "Masterclass/Clinic",
+    }
+}
+
+/// The entities [`Event`]s can be browsed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventBrowseBy {
+    Area(Mbid),
+    Artist(Mbid),
+    Collection(Mbid),
+    Place(Mbid),
+}
+
+impl BrowseFilter for EventBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match self {
+            EventBrowseBy::Area(mbid) => ("area", mbid),
+            EventBrowseBy::Artist(mbid) => ("artist", mbid),
+            EventBrowseBy::Collection(mbid) => ("collection", mbid),
+            EventBrowseBy::Place(mbid) => ("place", mbid),
+        }
+    }
+}
+
+/// The subqueries that can be included in an [`Event`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum EventInc {
+    Aliases,
+    Annotation,
+    Tags,
+    Genres,
+    Ratings,
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
+}
+
+impl Include for EventInc {}
+
+impl Entity for Event {
+    const NAME: &'static str = "event";
+    const PLURAL: &'static str = "events";
+    type BrowseBy = EventBrowseBy;
+    type Include = EventInc;
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::mock;
+
+    #[tokio::test]
+    async fn lookup_event() {
+        let server = MockServer::start().await;
+        mock::mount_json(
+            &server,
+            "event/4c1e4a1e-ae52-4d93-9f1a-bd6a6b8a4e5c",
+            r#"{
+                "id": "4c1e4a1e-ae52-4d93-9f1a-bd6a6b8a4e5c",
+                "name": "Tool at Madison Square Garden",
+                "type": "Concert",
+                "type-id": "ef55e8d7-3d00-394a-8012-f5506a29ff0b",
+                "time": "20:00",
+                "setlist": "* Fear Inoculum\n* Aenima",
+                "cancelled": false,
+                "life-span": {"begin": "2019-11-14", "end": "2019-11-14", "ended": true},
+                "disambiguation": ""
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let event: Event = client
+            .lookup(&"4c1e4a1e-ae52-4d93-9f1a-bd6a6b8a4e5c".parse().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(event.a_type, Some(EventType::Concert));
+        assert_eq!(event.time.as_deref(), Some("20:00"));
+        assert_eq!(event.setlist.as_deref(), Some("* Fear Inoculum\n* Aenima"));
+        assert!(!event.cancelled);
+    }
+}
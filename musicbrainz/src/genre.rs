@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{BrowseFilter, Entity, Include, Mbid};
+
+/// Genres are a curated subset of the folksonomy tags users can apply to entities, such as
+/// "drum and bass" or "progressive metal".
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Genre)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Genre {
+    /// The name of the genre, always in lowercase.
+    pub name: String,
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    #[serde(default)]
+    pub disambiguation: String,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Genres can't be browsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenreBrowseBy {}
+
+impl BrowseFilter for GenreBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match *self {}
+    }
+}
+
+/// Genre lookups don't support any subqueries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenreInc {}
+
+impl From<GenreInc> for &'static str {
+    fn from(inc: GenreInc) -> Self {
+        match inc {}
+    }
+}
+
+impl Include for GenreInc {}
+
+impl Entity for Genre {
+    const NAME: &'static str = "genre";
+    const PLURAL: &'static str = "genres";
+    type BrowseBy = GenreBrowseBy;
+    type Include = GenreInc;
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::mock;
+
+    #[tokio::test]
+    async fn lookup_genre() {
+        let server = MockServer::start().await;
+        mock::mount_json(
+            &server,
+            "genre/f66d7266-eb3d-4ef3-b4d8-b7cd992f918b",
+            r#"{
+                "id": "f66d7266-eb3d-4ef3-b4d8-b7cd992f918b",
+                "name": "progressive metal",
+                "disambiguation": ""
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let genre: Genre = client
+            .lookup(&"f66d7266-eb3d-4ef3-b4d8-b7cd992f918b".parse().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(genre.name, "progressive metal");
+        assert_eq!(genre.disambiguation, "");
+        assert!(genre.extra.is_empty());
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::Debug;
+
+/// A subquery that adds related data to the result of a lookup, such as the tracklist of a
+/// release.
+///
+/// Every [`Entity`](crate::Entity) has its own set of includes, so that only the subqueries
+/// MusicBrainz supports for it can be requested.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/MusicBrainz_API#Subqueries)
+pub trait Include: Into<&'static str> + Copy + Debug + Send + Sync + 'static {}
+
+/// Joins includes into the value of the `inc` query parameter.
+pub(crate) fn to_query<I: Include>(includes: &[I]) -> String {
+    includes
+        .iter()
+        .map(|&inc| inc.into())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
@@ -0,0 +1,1006 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    ops::Bound,
+};
+
+use futures::TryStreamExt;
+use lucene_query_builder::QueryString;
+
+use crate::{
+    alias::Alias, dump::DumpBackend, ArtistCredit, Entity, Mbid, MusicBrainzError, Page,
+    SearchResult,
+};
+
+/// An entity which can be added to a [`LocalIndex`].
+pub trait Indexed: Entity {
+    /// The field terms without an explicit field are matched against, e.g. `release`.
+    const DEFAULT_FIELD: &'static str;
+
+    fn mbid(&self) -> &Mbid;
+
+    /// Adds the searchable fields of the entity to `doc`, using the field names of the
+    /// [search API](https://musicbrainz.org/doc/MusicBrainz_API/Search).
+    fn index(&self, doc: &mut Document);
+}
+
+/// The searchable fields of an entity, as collected by [`Indexed::index`].
+#[derive(Debug, Default)]
+pub struct Document {
+    fields: Vec<(&'static str, Kind, String)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Text,
+    Keyword,
+}
+
+impl Document {
+    /// Adds free text, such as a title, which is matched word by word.
+    pub fn text(&mut self, field: &'static str, value: &str) {
+        self.fields.push((field, Kind::Text, value.to_string()));
+    }
+
+    /// Adds an identifier or code, such as a barcode or a date, which is matched as a whole.
+    pub fn keyword(&mut self, field: &'static str, value: &str) {
+        if !value.is_empty() {
+            self.fields.push((field, Kind::Keyword, value.to_string()));
+        }
+    }
+
+    /// Adds the credited names, and the names of the credited artists when they differ.
+    pub(crate) fn artist_credit(&mut self, credit: Option<&ArtistCredit>) {
+        for credited in credit.iter().flat_map(|credit| &credit.0) {
+            self.text("artist", &credited.name);
+            if credited.artist.name != credited.name {
+                self.text("artist", &credited.artist.name);
+            }
+        }
+    }
+
+    pub(crate) fn aliases(&mut self, aliases: Option<&[Alias]>) {
+        for alias in aliases.into_iter().flatten() {
+            self.text("alias", &alias.name);
+        }
+    }
+}
+
+/// An in-memory full-text index of entities, which answers the same Lucene queries as
+/// [`Entity::search`] without touching the network.
+///
+/// Queries support fields (`release:Lateralus`), phrases (`"fear inoculum"`), grouping, `AND`,
+/// `OR`, `NOT`, `+`/`-` prefixes, boosts (`^2`), wildcards (`later*`, `lat?ralus`) and ranges
+/// (`date:[2000 TO 2005]`). As with MusicBrainz, results are ranked with TF-IDF and scored from 0
+/// to 100 relative to the best match.
+///
+/// ```no_run
+/// # async fn example() -> Result<(), musicbrainz::MusicBrainzError> {
+/// use lucene_query_builder::QueryString;
+/// use musicbrainz::{index::LocalIndex, DumpBackend, Release};
+///
+/// let index: LocalIndex<Release> = LocalIndex::load(&DumpBackend::new("mbdump")).await?;
+/// let page = index
+///     .search(&QueryString("release:Lateralus AND artist:Tool".into()), 25, 0)
+///     .unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub struct LocalIndex<E> {
+    entities: Vec<E>,
+    ids: HashMap<Mbid, usize>,
+    fields: HashMap<&'static str, Field>,
+}
+
+struct Field {
+    kind: Kind,
+    /// The documents every term appears in, along with its positions in them.
+    terms: BTreeMap<String, Vec<Posting>>,
+    /// The number of terms in each document.
+    lengths: HashMap<usize, u32>,
+}
+
+struct Posting {
+    doc: usize,
+    positions: Vec<u32>,
+}
+
+/// A query which can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid query at offset {offset}: {message}")]
+pub struct ParseQueryError {
+    pub offset: usize,
+    pub message: String,
+}
+
+impl<E: Indexed> LocalIndex<E> {
+    pub fn new() -> Self {
+        Self {
+            entities: Vec::new(),
+            ids: HashMap::new(),
+            fields: HashMap::new(),
+        }
+    }
+
+    /// Indexes every entity of type `E` in a data dump.
+    pub async fn load(dump: &DumpBackend) -> Result<Self, MusicBrainzError> {
+        let mut index = Self::new();
+        dump.entities::<E>()
+            .await?
+            .try_for_each(|entity| {
+                index.insert(entity);
+                futures::future::ready(Ok(()))
+            })
+            .await?;
+        Ok(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub fn get(&self, mbid: &Mbid) -> Option<&E> {
+        self.ids.get(mbid).map(|&doc| &self.entities[doc])
+    }
+
+    /// Adds an entity to the index. Entities whose MBID is already indexed are ignored.
+    pub fn insert(&mut self, entity: E) {
+        if self.ids.contains_key(entity.mbid()) {
+            return;
+        }
+        let doc = self.entities.len();
+        let mut document = Document::default();
+        entity.index(&mut document);
+
+        let mut next_positions = HashMap::<&str, u32>::new();
+        for (name, kind, value) in document.fields {
+            let field = self.fields.entry(name).or_insert_with(|| Field {
+                kind,
+                terms: BTreeMap::new(),
+                lengths: HashMap::new(),
+            });
+            let tokens = analyze(field.kind, &value);
+            *field.lengths.entry(doc).or_default() += tokens.len() as u32;
+            // Values of multi-valued fields are kept apart, so phrases can't match across them
+            let next = next_positions.entry(name).or_default();
+            let start = *next;
+            *next += tokens.len() as u32 + 100;
+            for (i, token) in tokens.into_iter().enumerate() {
+                let postings = field.terms.entry(token).or_default();
+                match postings.last_mut() {
+                    Some(posting) if posting.doc == doc => posting.positions.push(start + i as u32),
+                    _ => postings.push(Posting {
+                        doc,
+                        positions: vec![start + i as u32],
+                    }),
+                }
+            }
+        }
+
+        self.ids.insert(*entity.mbid(), doc);
+        self.entities.push(entity);
+    }
+
+    /// Searches for entities matching a Lucene `query`, returning at most `limit` results
+    /// starting from `offset`, best matches first.
+    pub fn search(
+        &self,
+        query: &QueryString,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Page<SearchResult<&E>>, ParseQueryError> {
+        let query = Parser::new(&query.0).parse()?;
+        let mut hits: Vec<(usize, f32)> = self.eval(&query, E::DEFAULT_FIELD).into_iter().collect();
+        hits.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let best = hits.first().map_or(0.0, |hit| hit.1);
+        let items = hits
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .map(|&(doc, score)| SearchResult {
+                score: if best > 0.0 {
+                    (score / best * 100.0).round() as u8
+                } else {
+                    100
+                },
+                entity: &self.entities[doc],
+            })
+            .collect();
+        Ok(Page {
+            count: hits.len(),
+            offset,
+            items,
+        })
+    }
+
+    /// Finds the documents matching `query`, along with their scores.
+    fn eval(&self, query: &Query, default_field: &str) -> HashMap<usize, f32> {
+        match query {
+            Query::Term {
+                field,
+                text,
+                wildcard,
+            } => self.eval_term(field.as_deref().unwrap_or(default_field), text, *wildcard),
+            Query::Range { field, from, to } => {
+                self.eval_range(field.as_deref().unwrap_or(default_field), from, to)
+            }
+            Query::Boost(query, boost) => {
+                let mut docs = self.eval(query, default_field);
+                docs.values_mut().for_each(|score| *score *= boost);
+                docs
+            }
+            Query::Group { field, query } => {
+                self.eval(query, field.as_deref().unwrap_or(default_field))
+            }
+            Query::Bool(clauses) => self.eval_bool(clauses, default_field),
+        }
+    }
+
+    fn eval_term(&self, field: &str, text: &str, wildcard: bool) -> HashMap<usize, f32> {
+        let field = match self.fields.get(field) {
+            Some(field) => field,
+            None => return HashMap::new(),
+        };
+        if wildcard {
+            return self.eval_wildcard(field, text);
+        }
+        let tokens = analyze(field.kind, text);
+        match tokens.as_slice() {
+            [] => HashMap::new(),
+            tokens => self.eval_phrase(field, tokens),
+        }
+    }
+
+    /// Finds the documents with a term matching `pattern`, where `*` matches any characters and
+    /// `?` a single one.
+    fn eval_wildcard(&self, field: &Field, pattern: &str) -> HashMap<usize, f32> {
+        // Like Lucene, wildcard patterns aren't analyzed, only lowercased
+        let pattern = pattern.trim().to_lowercase();
+        let prefix = &pattern[..pattern.find(['*', '?']).unwrap_or(pattern.len())];
+        // Like Lucene, wildcard matches all score the same
+        let mut docs = HashMap::new();
+        for (_, postings) in field
+            .terms
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .take_while(|(term, _)| term.starts_with(prefix))
+            .filter(|(term, _)| wildcard_match(&pattern, term))
+        {
+            for posting in postings {
+                docs.insert(posting.doc, 1.0);
+            }
+        }
+        docs
+    }
+
+    /// Scores the documents containing `tokens` next to each other.
+    fn eval_phrase(&self, field: &Field, tokens: &[String]) -> HashMap<usize, f32> {
+        let mut postings = Vec::with_capacity(tokens.len());
+        for token in tokens {
+            match field.terms.get(token) {
+                Some(p) => postings.push(p),
+                None => return HashMap::new(),
+            }
+        }
+        let idf: f32 = postings.iter().map(|p| self.idf(p.len())).sum();
+
+        let mut docs = HashMap::new();
+        for first in postings[0] {
+            let rest: Option<Vec<&Posting>> = postings[1..]
+                .iter()
+                .map(|p| {
+                    p.binary_search_by_key(&first.doc, |posting| posting.doc)
+                        .ok()
+                        .map(|i| &p[i])
+                })
+                .collect();
+            let rest = match rest {
+                Some(rest) => rest,
+                None => continue,
+            };
+            let freq = first
+                .positions
+                .iter()
+                .filter(|&&start| {
+                    rest.iter()
+                        .enumerate()
+                        .all(|(i, p)| p.positions.contains(&(start + i as u32 + 1)))
+                })
+                .count();
+            if freq > 0 {
+                let length = field.lengths.get(&first.doc).copied().unwrap_or(1).max(1);
+                let score = (freq as f32).sqrt() * idf * idf / (length as f32).sqrt();
+                docs.insert(first.doc, score);
+            }
+        }
+        docs
+    }
+
+    fn eval_range(
+        &self,
+        field: &str,
+        from: &Bound<String>,
+        to: &Bound<String>,
+    ) -> HashMap<usize, f32> {
+        let field = match self.fields.get(field) {
+            Some(field) => field,
+            None => return HashMap::new(),
+        };
+        let bound = |bound: &Bound<String>| match bound {
+            Bound::Included(v) => Bound::Included(analyze(Kind::Keyword, v).concat()),
+            Bound::Excluded(v) => Bound::Excluded(analyze(Kind::Keyword, v).concat()),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        let (from, to) = (bound(from), bound(to));
+        if let (Bound::Included(f) | Bound::Excluded(f), Bound::Included(t) | Bound::Excluded(t)) =
+            (&from, &to)
+        {
+            if f > t {
+                return HashMap::new();
+            }
+        }
+
+        let mut docs = HashMap::new();
+        for postings in field.terms.range((from, to)).map(|(_, p)| p) {
+            for posting in postings {
+                docs.insert(posting.doc, 1.0);
+            }
+        }
+        docs
+    }
+
+    fn eval_bool(&self, clauses: &[(Occur, Query)], default_field: &str) -> HashMap<usize, f32> {
+        let mut must = None::<HashMap<usize, f32>>;
+        let mut should = Vec::new();
+        let mut must_not = HashSet::new();
+        for (occur, query) in clauses {
+            let docs = self.eval(query, default_field);
+            match occur {
+                Occur::Must => {
+                    must = Some(match must {
+                        None => docs,
+                        Some(must) => must
+                            .into_iter()
+                            .filter_map(|(doc, score)| docs.get(&doc).map(|s| (doc, score + s)))
+                            .collect(),
+                    })
+                }
+                Occur::Should => should.push(docs),
+                Occur::MustNot => must_not.extend(docs.into_keys()),
+            }
+        }
+
+        let mut docs = match must {
+            Some(mut docs) => {
+                for should in &should {
+                    for (doc, score) in docs.iter_mut() {
+                        *score += should.get(doc).copied().unwrap_or_default();
+                    }
+                }
+                docs
+            }
+            None if !should.is_empty() => {
+                // Documents matching more of the optional clauses rank higher
+                let mut matched = HashMap::<usize, (f32, usize)>::new();
+                for should in &should {
+                    for (&doc, &score) in should {
+                        let m = matched.entry(doc).or_default();
+                        m.0 += score;
+                        m.1 += 1;
+                    }
+                }
+                let total = should.len() as f32;
+                matched
+                    .into_iter()
+                    .map(|(doc, (score, n))| (doc, score * n as f32 / total))
+                    .collect()
+            }
+            // A purely negative query matches everything else
+            None => (0..self.entities.len()).map(|doc| (doc, 0.0)).collect(),
+        };
+        docs.retain(|doc, _| !must_not.contains(doc));
+        docs
+    }
+
+    fn idf(&self, doc_freq: usize) -> f32 {
+        1.0 + (self.entities.len() as f32 / (doc_freq as f32 + 1.0)).ln()
+    }
+}
+
+impl<E: Indexed> Default for LocalIndex<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Indexed> Extend<E> for LocalIndex<E> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|entity| self.insert(entity));
+    }
+}
+
+impl<E: Indexed> FromIterator<E> for LocalIndex<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let mut index = Self::new();
+        index.extend(iter);
+        index
+    }
+}
+
+impl<E> fmt::Debug for LocalIndex<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalIndex")
+            .field("entities", &self.entities.len())
+            .field("fields", &self.fields.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Splits a value into the terms it is indexed and searched by.
+fn analyze(kind: Kind, value: &str) -> Vec<String> {
+    match kind {
+        Kind::Keyword => vec![value.trim().to_lowercase()],
+        Kind::Text => value
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect(),
+    }
+}
+
+/// Whether `term` matches `pattern`, where `*` matches any characters and `?` a single one.
+fn wildcard_match(pattern: &str, term: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let term: Vec<char> = term.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Where to resume from if the characters after the last `*` stop matching
+    let mut star = None;
+    while t < term.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == term[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Query {
+    /// A single term, or a phrase if it analyzes to several. Wildcard terms are matched against
+    /// single indexed terms.
+    Term {
+        field: Option<String>,
+        text: String,
+        wildcard: bool,
+    },
+    /// Terms between two bounds, where `*` is unbounded, e.g. `date:[2000 TO 2005}`.
+    Range {
+        field: Option<String>,
+        from: Bound<String>,
+        to: Bound<String>,
+    },
+    /// A parenthesized query, possibly applying a field to all its terms, e.g. `release:(a b)`.
+    Group {
+        field: Option<String>,
+        query: Box<Query>,
+    },
+    Boost(Box<Query>, f32),
+    Bool(Vec<(Occur, Query)>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Occur {
+    Must,
+    Should,
+    MustNot,
+}
+
+/// A recursive descent parser for the Lucene query syntax, following the semantics of Lucene's
+/// classic query parser.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn parse(mut self) -> Result<Query, ParseQueryError> {
+        let query = self.clauses()?;
+        self.skip_whitespace();
+        match self.peek() {
+            None => Ok(query),
+            Some(c) => Err(self.error(format!("unexpected `{}`", c))),
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseQueryError {
+        ParseQueryError {
+            offset: self.pos,
+            message: message.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    /// Consumes `keyword` if it is the next word.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let rest = &self.input[self.pos..];
+        let boundary = !matches!(
+            rest[keyword.len().min(rest.len())..].chars().next(),
+            Some(c) if !(c.is_whitespace() || c == '(' || c == '"')
+        );
+        if rest.starts_with(keyword) && boundary {
+            self.pos += keyword.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// A sequence of clauses, up to the end of the input or a closing parenthesis.
+    fn clauses(&mut self) -> Result<Query, ParseQueryError> {
+        let mut clauses: Vec<(Occur, Query)> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if matches!(self.peek(), None | Some(')')) {
+                break;
+            }
+
+            let conjunction = if self.eat_keyword("AND") || self.eat_keyword("&&") {
+                Some(Occur::Must)
+            } else if self.eat_keyword("OR") || self.eat_keyword("||") {
+                Some(Occur::Should)
+            } else {
+                None
+            };
+            if conjunction.is_some() {
+                if clauses.is_empty() {
+                    return Err(self.error("expected a clause before the operator"));
+                }
+                self.skip_whitespace();
+            }
+
+            let mut occur = if self.eat('+') {
+                Occur::Must
+            } else if self.eat('-') || self.eat('!') || self.eat_keyword("NOT") {
+                Occur::MustNot
+            } else {
+                conjunction.unwrap_or(Occur::Should)
+            };
+            if occur == Occur::Should && conjunction == Some(Occur::Must) {
+                occur = Occur::Must;
+            }
+            self.skip_whitespace();
+
+            // `a AND b` makes both sides required, unless they are prohibited
+            if conjunction == Some(Occur::Must) {
+                if let Some(last) = clauses.last_mut() {
+                    if last.0 == Occur::Should {
+                        last.0 = Occur::Must;
+                    }
+                }
+            }
+
+            let clause = self.clause()?;
+            clauses.push((occur, clause));
+        }
+
+        match clauses.as_slice() {
+            [] => Err(self.error("expected a query")),
+            [(Occur::Should | Occur::Must, _)] => Ok(clauses.pop().expect("one clause").1),
+            _ => Ok(Query::Bool(clauses)),
+        }
+    }
+
+    /// A single, possibly fielded and boosted, term, phrase, range or group.
+    fn clause(&mut self) -> Result<Query, ParseQueryError> {
+        let start = self.pos;
+        let mut field = None;
+        if !matches!(self.peek(), Some('(' | '"' | '[' | '{')) {
+            let word = self.word()?;
+            if self.eat(':') {
+                field = Some(word);
+            } else {
+                self.pos = start;
+            }
+        }
+
+        let query = match self.peek() {
+            Some('(') => {
+                self.bump();
+                let query = self.clauses()?;
+                if !self.eat(')') {
+                    return Err(self.error("expected `)`"));
+                }
+                Query::Group {
+                    field,
+                    query: Box::new(query),
+                }
+            }
+            Some('"') => {
+                self.bump();
+                let mut text = String::new();
+                loop {
+                    match self.bump() {
+                        Some('"') => break,
+                        Some('\\') => text.extend(self.bump()),
+                        Some(c) => text.push(c),
+                        None => return Err(self.error("unterminated phrase")),
+                    }
+                }
+                // Proximity isn't supported, so `"a b"~2` is treated as an exact phrase
+                if self.eat('~') {
+                    self.number();
+                }
+                Query::Term {
+                    field,
+                    text,
+                    wildcard: false,
+                }
+            }
+            Some(open @ ('[' | '{')) => {
+                self.bump();
+                self.skip_whitespace();
+                let from = self.word()?;
+                self.skip_whitespace();
+                if !self.eat_keyword("TO") {
+                    return Err(self.error("expected `TO`"));
+                }
+                self.skip_whitespace();
+                let to = self.word()?;
+                self.skip_whitespace();
+                // Like Lucene, each end can be inclusive or exclusive independently
+                let close = match self.bump() {
+                    Some(close @ (']' | '}')) => close,
+                    _ => return Err(self.error("expected `]` or `}`")),
+                };
+                let bound = |v: String, inclusive| match v.as_str() {
+                    "*" => Bound::Unbounded,
+                    _ if inclusive => Bound::Included(v),
+                    _ => Bound::Excluded(v),
+                };
+                Query::Range {
+                    field,
+                    from: bound(from, open == '['),
+                    to: bound(to, close == ']'),
+                }
+            }
+            _ => {
+                let text = self.word()?;
+                // Fuzziness isn't supported, so `term~` is treated as `term`
+                if self.eat('~') {
+                    self.number();
+                }
+                let wildcard = text.contains(['*', '?']);
+                Query::Term {
+                    field,
+                    text,
+                    wildcard,
+                }
+            }
+        };
+
+        if self.eat('^') {
+            let boost = self
+                .number()
+                .ok_or_else(|| self.error("expected a boost"))?;
+            Ok(Query::Boost(Box::new(query), boost))
+        } else {
+            Ok(query)
+        }
+    }
+
+    /// A bare term, which ends at whitespace or any special character.
+    fn word(&mut self) -> Result<String, ParseQueryError> {
+        let mut word = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '\\' => {
+                    self.bump();
+                    word.extend(self.bump());
+                }
+                c if c.is_whitespace() => break,
+                '(' | ')' | '[' | ']' | '{' | '}' | ':' | '"' | '^' | '~' => break,
+                c => {
+                    self.bump();
+                    word.push(c);
+                }
+            }
+        }
+        if word.is_empty() {
+            Err(self.error("expected a term"))
+        } else {
+            Ok(word)
+        }
+    }
+
+    fn number(&mut self) -> Option<f32> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit() || c == '.') {
+            self.bump();
+        }
+        self.input[start..self.pos].parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Release, ReleaseGroup};
+
+    fn parse(query: &str) -> Query {
+        Parser::new(query).parse().unwrap()
+    }
+
+    fn term(field: Option<&str>, text: &str) -> Query {
+        Query::Term {
+            field: field.map(str::to_string),
+            text: text.to_string(),
+            wildcard: false,
+        }
+    }
+
+    #[test]
+    fn parse_queries() {
+        assert_eq!(parse("Lateralus"), term(None, "Lateralus"));
+        assert_eq!(
+            parse("release:\"Fear Inoculum\""),
+            term(Some("release"), "Fear Inoculum")
+        );
+        assert_eq!(
+            parse("release:Lateralus AND country:US"),
+            Query::Bool(vec![
+                (Occur::Must, term(Some("release"), "Lateralus")),
+                (Occur::Must, term(Some("country"), "US")),
+            ])
+        );
+        assert_eq!(
+            parse("tool -live +catno:61422-31160-2^2"),
+            Query::Bool(vec![
+                (Occur::Should, term(None, "tool")),
+                (Occur::MustNot, term(None, "live")),
+                (
+                    Occur::Must,
+                    Query::Boost(Box::new(term(Some("catno"), "61422-31160-2")), 2.0)
+                ),
+            ])
+        );
+        assert_eq!(
+            parse("date:[2000 TO *}"),
+            Query::Range {
+                field: Some("date".to_string()),
+                from: Bound::Included("2000".to_string()),
+                to: Bound::Unbounded,
+            }
+        );
+        assert_eq!(
+            parse("artist:(tool OR \"a perfect circle\")"),
+            Query::Group {
+                field: Some("artist".to_string()),
+                query: Box::new(Query::Bool(vec![
+                    (Occur::Should, term(None, "tool")),
+                    (Occur::Should, term(None, "a perfect circle")),
+                ])),
+            }
+        );
+        assert_eq!(
+            parse("release:lat?ra*s"),
+            Query::Term {
+                field: Some("release".to_string()),
+                text: "lat?ra*s".to_string(),
+                wildcard: true,
+            }
+        );
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(wildcard_match("lat*", "lateralus"));
+        assert!(wildcard_match("lat?ralus", "lateralus"));
+        assert!(wildcard_match("l*r*s", "lateralus"));
+        assert!(wildcard_match("*", ""));
+        assert!(!wildcard_match("lat?", "lateralus"));
+        assert!(!wildcard_match("l*x", "lateralus"));
+        assert!(!wildcard_match("lateralus?", "lateralus"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        for query in [
+            "",
+            "release:",
+            "(tool",
+            "\"tool",
+            "AND tool",
+            "date:[2000 2001]",
+        ] {
+            assert!(Parser::new(query).parse().is_err(), "{}", query);
+        }
+    }
+
+    fn release(id: &str, title: &str, artist: &str, date: &str, catno: &str) -> Release {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "title": title,
+            "date": date,
+            "barcode": "",
+            "text-representation": {"language": "eng", "script": "Latn"},
+            "artist-credit": [{
+                "name": artist,
+                "artist": {
+                    "id": "66fc5bf8-daa4-4241-b378-9bc9077939d2",
+                    "name": artist,
+                    "sort-name": artist
+                }
+            }],
+            "label-info": [{"catalog-number": catno, "label": null}]
+        }))
+        .unwrap()
+    }
+
+    fn releases() -> LocalIndex<Release> {
+        [
+            release(
+                "2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                "Lateralus",
+                "Tool",
+                "2001-05-15",
+                "61422-31160-2",
+            ),
+            release(
+                "3c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                "Fear Inoculum",
+                "Tool",
+                "2019-08-30",
+                "19075950402",
+            ),
+            release(
+                "4c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                "Lateralus (live)",
+                "Tool Tribute",
+                "2005",
+                "",
+            ),
+            release(
+                "5c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                "Mer de Noms",
+                "A Perfect Circle",
+                "2000-05-23",
+                "",
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn titles(index: &LocalIndex<Release>, query: &str) -> Vec<(String, u8)> {
+        index
+            .search(&QueryString(query.to_string()), 25, 0)
+            .unwrap()
+            .items
+            .into_iter()
+            .map(|result| (result.entity.title.clone(), result.score))
+            .collect()
+    }
+
+    #[test]
+    fn search_ranks_matches() {
+        let index = releases();
+        assert_eq!(
+            titles(&index, "lateralus"),
+            [
+                ("Lateralus".to_string(), 100),
+                ("Lateralus (live)".to_string(), 71)
+            ]
+        );
+        assert_eq!(
+            titles(&index, "release:lateralus AND artist:tool"),
+            [
+                ("Lateralus".to_string(), 100),
+                ("Lateralus (live)".to_string(), 71)
+            ]
+        );
+        assert_eq!(
+            titles(&index, "lateralus -live"),
+            [("Lateralus".to_string(), 100)]
+        );
+        assert_eq!(
+            titles(&index, "artist:\"perfect circle\""),
+            [("Mer de Noms".to_string(), 100)]
+        );
+        assert!(titles(&index, "artist:\"circle perfect\"").is_empty());
+    }
+
+    #[test]
+    fn search_fields() {
+        let index = releases();
+        let titles = |query| -> Vec<String> {
+            titles(&index, query)
+                .into_iter()
+                .map(|(title, _)| title)
+                .collect()
+        };
+        assert_eq!(titles("catno:61422-31160-2"), ["Lateralus"]);
+        assert_eq!(titles("fea*"), ["Fear Inoculum"]);
+        assert_eq!(titles("lat?ralus"), ["Lateralus", "Lateralus (live)"]);
+        assert_eq!(titles("l*us"), ["Lateralus", "Lateralus (live)"]);
+        assert_eq!(titles("i*m"), ["Fear Inoculum"]);
+        assert!(titles("lat?").is_empty());
+        assert_eq!(
+            titles("date:[2001 TO 2019}"),
+            ["Lateralus", "Lateralus (live)"]
+        );
+        assert_eq!(
+            titles("reid:3c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17"),
+            ["Fear Inoculum"]
+        );
+        assert!(titles("nosuchfield:tool").is_empty());
+    }
+
+    #[test]
+    fn search_pages() {
+        let index = releases();
+        let page = index
+            .search(&QueryString("artist:tool".into()), 1, 1)
+            .unwrap();
+        assert_eq!(page.count, 3);
+        assert_eq!(page.offset, 1);
+        assert_eq!(page.items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn load_dump() {
+        let dump = DumpBackend::new(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/fixtures/dump"));
+        let index = LocalIndex::<ReleaseGroup>::load(&dump).await.unwrap();
+        assert_eq!(index.len(), 1);
+        let page = index
+            .search(&QueryString("releasegroup:lateralus".into()), 25, 0)
+            .unwrap();
+        assert_eq!(page.items[0].entity.title, "Lateralus");
+        assert_eq!(page.items[0].score, 100);
+    }
+}
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alias::Alias, vocabulary::vocabulary, BrowseFilter, Entity, Include, Mbid, Relationship,
+};
+
+/// Instruments are devices created or adapted to make musical sounds. They are used in
+/// relationships to credit the performers of a recording or release.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Instrument)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Instrument {
+    /// The name of the instrument, e.g. "violin".
+    pub name: String,
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    #[serde(rename = "type")]
+    pub a_type: Option<InstrumentType>,
+    pub type_id: Option<Mbid>,
+    /// A brief description of the instrument.
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub disambiguation: String,
+    /// See [`InstrumentInc::Aliases`].
+    pub aliases: Option<Vec<Alias>>,
+    /// Links to other entities and URLs, see the `*Rels` variants of [`InstrumentInc`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+vocabulary! {
+    pub enum InstrumentType {
+        WindInstrument = "Wind instrument",
+        StringInstrument = "String instrument",
+        PercussionInstrument = "Percussion instrument",
+        ElectronicInstrument = "Electronic instrument",
+        /// A grouping of related but distinct instruments, e.g. "recorder".
+        Family = "Family",
+        /// A standard grouping of instruments often played together, e.g. "string quartet".
+        Ensemble = "Ensemble",
+        OtherInstrument = "Other instrument",
+    }
+}
+
+/// The entities [`Instrument`]s can be browsed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstrumentBrowseBy {
+    Collection(Mbid),
+}
+
+impl BrowseFilter for InstrumentBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match self {
+            InstrumentBrowseBy::Collection(mbid) => ("collection", mbid),
+        }
+    }
+}
+
+/// The subqueries that can be included in an [`Instrument`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum InstrumentInc {
+    Aliases,
+    Annotation,
+    Tags,
+    Genres,
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
+}
+
+impl Include for InstrumentInc {}
+
+impl Entity for Instrument {
+    const NAME: &'static str = "instrument";
+    const PLURAL: &'static str = "instruments";
+    type BrowseBy = InstrumentBrowseBy;
+    type Include = InstrumentInc;
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::mock;
+
+    #[tokio::test]
+    async fn lookup_instrument() {
+        let server = MockServer::start().await;
+        mock::mount_lookup(
+            &server,
+            "instrument/63021302-86cd-4aee-80df-2270d54f4978",
+            "aliases",
+            r#"{
+                "id": "63021302-86cd-4aee-80df-2270d54f4978",
+                "name": "guitar",
+                "type": "String instrument",
+                "type-id": "cc00f97f-cb4c-3a3d-9ad4-0c4b4ba1f1e4",
+                "description": "Plucked string instrument with a long neck and a flat body.",
+                "disambiguation": "",
+                "aliases": [{
+                    "name": "gitarr",
+                    "sort-name": "gitarr",
+                    "type": "Instrument name",
+                    "type-id": "2322fc94-fbf3-3c09-b23c-aa5ec8d14fcd",
+                    "locale": "et",
+                    "primary": true,
+                    "begin": null,
+                    "end": null,
+                    "ended": false
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let instrument: Instrument = client
+            .lookup_with(
+                &"63021302-86cd-4aee-80df-2270d54f4978".parse().unwrap(),
+                &[InstrumentInc::Aliases],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(instrument.a_type, Some(InstrumentType::StringInstrument));
+        assert!(instrument
+            .description
+            .starts_with("Plucked string instrument"));
+        let alias = &instrument.aliases.unwrap()[0];
+        assert_eq!(alias.locale.as_deref(), Some("et"));
+        assert_eq!(alias.primary, Some(true));
+    }
+}
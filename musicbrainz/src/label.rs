@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alias::Alias,
+    index::{Document, Indexed},
+    life_span::LifeSpan,
+    vocabulary::vocabulary,
+    Area, BrowseFilter, Entity, Include, Mbid, Relationship, Release,
+};
+
+/// Labels are one of the most complicated and controversial parts of the music industry. The main
+/// reason for that being that the term itself is not clearly defined and refers to at least two
+/// overlapping concepts: imprints, and the companies that control them. In MusicBrainz, a label
+/// is mainly used to represent the imprint a release was issued under, as printed on it.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Label)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Label {
+    /// The official name of the label.
+    pub name: String,
+    pub sort_name: Option<String>,
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    #[serde(rename = "type")]
+    pub a_type: Option<LabelType>,
+    pub type_id: Option<Mbid>,
+    /// The [Label Code](https://musicbrainz.org/doc/Label/Label_Code), without the "LC" prefix.
+    pub label_code: Option<u32>,
+    /// The [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code of the
+    /// country the label is based in.
+    pub country: Option<String>,
+    /// The area the label is based in.
+    pub area: Option<Area>,
+    /// When the label was founded and dissolved.
+    pub life_span: Option<LifeSpan>,
+    #[serde(default)]
+    pub disambiguation: String,
+    /// [Interested Parties Information](https://musicbrainz.org/doc/IPI) codes.
+    #[serde(default)]
+    pub ipis: Vec<String>,
+    /// [International Standard Name Identifiers](https://musicbrainz.org/doc/ISNI).
+    #[serde(default)]
+    pub isnis: Vec<String>,
+    /// See [`LabelInc::Aliases`].
+    pub aliases: Option<Vec<Alias>>,
+    /// See [`LabelInc::Releases`].
+    pub releases: Option<Vec<Release>>,
+    /// Links to other entities and URLs, see the `*Rels` variants of [`LabelInc`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+vocabulary! {
+    pub enum LabelType {
+        /// A label name which is used as a brand, rather than being a company of its own.
+        Imprint = "Imprint",
+        /// A label whose main activity is producing releases, for labels that don't fit any of the
+        /// more specific production types.
+        Production = "Production",
+        /// A production label that produces entirely new releases.
+        OriginalProduction = "Original Production",
+        /// A production label that produces unofficial releases.
+        BootlegProduction = "Bootleg Production",
+        /// A production label that reissues releases that have been released before.
+        ReissueProduction = "Reissue Production",
+        /// A company that distributes releases, but doesn't produce them.
+        Distributor = "Distributor",
+        /// A company that owns other labels, generally without releasing under its own name.
+        Holding = "Holding",
+        /// An organization that collects royalties on behalf of artists.
+        RightsSociety = "Rights Society",
+        /// A company that publishes works, rather than releases.
+        Publisher = "Publisher",
+        /// A company that physically manufactures releases, such as a pressing plant.
+        Manufacturer = "Manufacturer",
+    }
+}
+
+/// The entities [`Label`]s can be browsed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelBrowseBy {
+    Area(Mbid),
+    Collection(Mbid),
+    Release(Mbid),
+}
+
+impl BrowseFilter for LabelBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match self {
+            LabelBrowseBy::Area(mbid) => ("area", mbid),
+            LabelBrowseBy::Collection(mbid) => ("collection", mbid),
+            LabelBrowseBy::Release(mbid) => ("release", mbid),
+        }
+    }
+}
+
+/// The subqueries that can be included in a [`Label`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum LabelInc {
+    /// The releases issued by the label.
+    Releases,
+    Aliases,
+    Annotation,
+    Tags,
+    Genres,
+    Ratings,
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
+}
+
+impl Include for LabelInc {}
+
+impl Entity for Label {
+    const NAME: &'static str = "label";
+    const PLURAL: &'static str = "labels";
+    type BrowseBy = LabelBrowseBy;
+    type Include = LabelInc;
+}
+
+impl Indexed for Label {
+    const DEFAULT_FIELD: &'static str = "label";
+
+    fn mbid(&self) -> &Mbid {
+        &self.id
+    }
+
+    fn index(&self, doc: &mut Document) {
+        doc.text("label", &self.name);
+        doc.aliases(self.aliases.as_deref());
+        doc.keyword("country", self.country.as_deref().unwrap_or_default());
+        if let Some(code) = self.label_code {
+            doc.keyword("code", &code.to_string());
+        }
+        doc.keyword("laid", &self.id.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::mock;
+
+    #[tokio::test]
+    async fn lookup_label() {
+        let server = MockServer::start().await;
+        mock::mount_lookup(
+            &server,
+            "label/4bf8c1b8-0a5a-4e1b-9a3e-6c0fa5cc2d5a",
+            "aliases",
+            r#"{
+                "id": "4bf8c1b8-0a5a-4e1b-9a3e-6c0fa5cc2d5a",
+                "name": "Volcano Entertainment",
+                "sort-name": "Volcano Entertainment",
+                "type": "Original Production",
+                "type-id": "7aaa37fe-2def-3476-b359-80245850062d",
+                "label-code": 6098,
+                "country": "US",
+                "area": {
+                    "id": "489ce91b-6658-3307-9877-795b68554c98",
+                    "name": "United States",
+                    "sort-name": "United States",
+                    "iso-3166-1-codes": ["US"],
+                    "disambiguation": ""
+                },
+                "life-span": {"begin": "1996", "end": "2013", "ended": true},
+                "disambiguation": "",
+                "ipis": [],
+                "isnis": [],
+                "aliases": [{
+                    "name": "Volcano",
+                    "sort-name": "Volcano",
+                    "type": "Search hint",
+                    "type-id": "829662f2-a781-3ec8-8b46-fbcea6196f81",
+                    "locale": null,
+                    "primary": null,
+                    "begin": null,
+                    "end": null,
+                    "ended": false
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let label: Label = client
+            .lookup_with(
+                &"4bf8c1b8-0a5a-4e1b-9a3e-6c0fa5cc2d5a".parse().unwrap(),
+                &[LabelInc::Aliases],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(label.a_type, Some(LabelType::OriginalProduction));
+        assert_eq!(label.label_code, Some(6098));
+        assert_eq!(label.area.unwrap().name, "United States");
+        let life_span = label.life_span.unwrap();
+        assert_eq!(life_span.begin.map(|d| d.year), Some(Some(1996)));
+        assert_eq!(life_span.end.map(|d| d.year), Some(Some(2013)));
+        assert_eq!(life_span.ended, Some(true));
+        assert_eq!(label.aliases.unwrap()[0].name, "Volcano");
+        assert!(label.releases.is_none());
+    }
+}
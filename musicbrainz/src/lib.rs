@@ -1,16 +1,58 @@
+pub mod alias;
 pub mod area;
+pub mod artist;
+pub mod artist_credit;
+pub mod backend;
+pub mod browse;
+pub mod cache;
+pub mod date;
+pub mod dump;
+pub mod event;
+pub mod genre;
+pub mod include;
+pub mod index;
+pub mod instrument;
+pub mod label;
+pub mod life_span;
 pub mod mbid;
+pub mod medium;
+#[cfg(test)]
+mod mock;
+pub mod page;
+pub mod place;
+pub mod recording;
+pub mod relationship;
 pub mod release;
 pub mod release_group;
+pub mod replay;
+pub mod retry;
+pub mod search;
+pub mod series;
+pub mod strict;
+pub mod url;
+mod vocabulary;
+pub mod work;
 
-use std::{error::Error, sync::Arc};
+use std::{error::Error, sync::Arc, time::Duration};
 
+use futures::Stream;
 use lucene_query_builder::QueryString;
-use reqwest::{Method, Request, Response};
-use serde::Deserialize;
-use tower::{util::BoxService, Service, ServiceExt};
+use reqwest::{header::HeaderMap, Method, Request, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use tower::{
+    buffer::Buffer,
+    util::{BoxCloneService, BoxLayer, BoxService},
+    BoxError, Layer, Service, ServiceExt,
+};
 
-pub use crate::{area::Area, mbid::Mbid, release::Release, release_group::ReleaseGroup};
+pub use crate::{
+    area::Area, artist::Artist, artist_credit::ArtistCredit, backend::Backend,
+    browse::BrowseFilter, cache::Cache, date::PartialDate, dump::DumpBackend, event::Event,
+    genre::Genre, include::Include, index::LocalIndex, instrument::Instrument, label::Label,
+    mbid::Mbid, page::Page, place::Place, recording::Recording, relationship::Relationship,
+    release::Release, release_group::ReleaseGroup, retry::MusicBrainzRetry, search::SearchResult,
+    series::Series, url::Url, work::Work,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum MusicBrainzError {
@@ -20,154 +62,1343 @@ pub enum MusicBrainzError {
     ClientReady(#[source] Arc<dyn Error + Send + Sync>),
     #[error("Failed to GET from MusicBrainz")]
     ClientGet(#[source] Arc<dyn Error + Send + Sync>),
+    #[error("Failed to read response body from MusicBrainz")]
+    Body(#[source] reqwest::Error),
+    #[error("No entity with MBID {0} exists")]
+    NotFound(Mbid),
+    /// MusicBrainz is throttling our requests, see [the rate
+    /// limiting](https://musicbrainz.org/doc/MusicBrainz_API/Rate_Limiting) rules.
+    #[error("Rate limited by MusicBrainz")]
+    RateLimited {
+        /// How long MusicBrainz asked us to wait before retrying, if it said.
+        retry_after: Option<Duration>,
+    },
+    #[error("MusicBrainz is unavailable")]
+    ServiceUnavailable,
+    /// The request isn't cached, and the [`cache::Cache`] is offline.
+    #[error("{0} is not cached")]
+    NotCached(reqwest::Url),
+    /// The request has no fixture, and the [`replay::Replay`] isn't recording.
+    #[error("No fixture recorded for {0}")]
+    NotRecorded(reqwest::Url),
+    #[error("Failed to read data dump")]
+    DumpIo(#[source] std::io::Error),
+    #[error("Failed to parse data dump entity as JSON")]
+    DumpParse(#[source] serde_json::Error),
+    /// In strict mode, a response or dump entity which doesn't exactly match our types.
+    #[error("Data doesn't match the expected types")]
+    Strict(#[source] strict::StrictError),
+    /// Any other unsuccessful response, along with the error message MusicBrainz sent.
+    #[error("MusicBrainz responded with {status}: {message}")]
+    Http { status: StatusCode, message: String },
     #[error("Failed to parse lookup url")]
-    LookupParseUrl(#[source] url::ParseError),
+    LookupParseUrl(#[source] ::url::ParseError),
     #[error("Failed to parse lookup response as JSON")]
     LookupParseResponse(#[source] serde_json::Error),
+    #[error("Failed to parse search url")]
+    SearchParseUrl(#[source] ::url::ParseError),
+    #[error("Failed to parse search response as JSON")]
+    SearchParseResponse(#[source] serde_json::Error),
+    #[error("Failed to parse browse url")]
+    BrowseParseUrl(#[source] ::url::ParseError),
+    #[error("Failed to parse browse response as JSON")]
+    BrowseParseResponse(#[source] serde_json::Error),
+}
+
+impl MusicBrainzError {
+    /// Builds the error for an unsuccessful response from MusicBrainz.
+    fn from_response(status: StatusCode, headers: &HeaderMap, body: &str) -> Self {
+        #[derive(Deserialize)]
+        struct ErrorBody {
+            error: String,
+        }
+
+        let retry_after = retry::retry_after(headers);
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited { retry_after },
+            StatusCode::SERVICE_UNAVAILABLE if retry_after.is_some() => {
+                Self::RateLimited { retry_after }
+            }
+            StatusCode::SERVICE_UNAVAILABLE => Self::ServiceUnavailable,
+            _ => {
+                let message = match serde_json::from_str::<ErrorBody>(body) {
+                    Ok(body) => body.error,
+                    Err(_) if !body.trim().is_empty() => body.trim().to_string(),
+                    Err(_) => status.canonical_reason().unwrap_or_default().to_string(),
+                };
+                Self::Http { status, message }
+            }
+        }
+    }
+
+    /// Turns a 404 into [`MusicBrainzError::NotFound`] for the entity that was requested.
+    fn or_not_found(self, mbid: &Mbid) -> Self {
+        match self {
+            Self::Http {
+                status: StatusCode::NOT_FOUND,
+                ..
+            } => Self::NotFound(*mbid),
+            e => e,
+        }
+    }
 }
 
 #[async_trait::async_trait]
 pub trait Entity
 where
     for<'de> Self: Deserialize<'de>,
-    Self: Send ,
+    Self: Serialize + Send + 'static,
 {
     const NAME: &'static str;
+    /// The key under which lists of this entity are returned, e.g. `releases`.
+    const PLURAL: &'static str;
+
+    /// The entities this one can be browsed by.
+    type BrowseBy: BrowseFilter;
+    /// The subqueries that can be included in a lookup of this entity.
+    type Include: Include;
 
     #[tracing::instrument(skip(client))]
-    async fn lookup(client: &mut Client, mbid: &Mbid) -> Result<Self, MusicBrainzError> {
-        let lookup_url = reqwest::Url::parse(&format!(
-            "https://musicbrainz.org/ws/2/{}/{}",
-            Self::NAME,
-            mbid
-        ))
-        .map_err(MusicBrainzError::LookupParseUrl)?;
-        tracing::debug!(%lookup_url);
+    async fn lookup(client: &Client, mbid: &Mbid) -> Result<Self, MusicBrainzError> {
+        Self::lookup_with(client, mbid, &[]).await
+    }
 
-        let res = client.get(lookup_url).await?;
-        tracing::debug!(?res);
+    /// Looks up an entity along with the related data selected by `includes`.
+    #[tracing::instrument(skip(client))]
+    async fn lookup_with(
+        client: &Client,
+        mbid: &Mbid,
+        includes: &[Self::Include],
+    ) -> Result<Self, MusicBrainzError> {
+        let mut lookup_url = client
+            .ws_url(&format!("{}/{}", Self::NAME, mbid))
+            .map_err(MusicBrainzError::LookupParseUrl)?;
+        if !includes.is_empty() {
+            lookup_url
+                .query_pairs_mut()
+                .append_pair("inc", &include::to_query(includes));
+        }
+        tracing::debug!(%lookup_url);
 
-        let text = res.text().await.unwrap(); // FIXME
+        let text = client
+            .get_text(lookup_url)
+            .await
+            .map_err(|e| e.or_not_found(mbid))?;
         tracing::trace!(text);
 
-        serde_json::from_str(&text).map_err(MusicBrainzError::LookupParseResponse)
+        if client.strict {
+            strict::from_str(&text).map_err(MusicBrainzError::Strict)
+        } else {
+            serde_json::from_str(&text).map_err(MusicBrainzError::LookupParseResponse)
+        }
     }
 
+    /// Searches for entities matching a Lucene `query`, returning at most `limit` results
+    /// starting from `offset`.
+    ///
+    /// MusicBrainz caps `limit` at 100.
     #[tracing::instrument(skip(client))]
-    #[allow(unused)]
     async fn search(
-        client: &mut Client,
+        client: &Client,
         query: QueryString,
         limit: usize,
         offset: usize,
-    ) -> Result<Self, MusicBrainzError> {
-        todo!()
+    ) -> Result<Page<SearchResult<Self>>, MusicBrainzError> {
+        let mut search_url = client
+            .ws_url(Self::NAME)
+            .map_err(MusicBrainzError::SearchParseUrl)?;
+        search_url
+            .query_pairs_mut()
+            .append_pair("query", &query.0)
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+        tracing::debug!(%search_url);
+
+        let text = client.get_text(search_url).await?;
+        tracing::trace!(text);
+
+        client.parse_page(
+            &text,
+            "count",
+            "offset",
+            Self::PLURAL,
+            MusicBrainzError::SearchParseResponse,
+        )
     }
 
+    /// Lists the entities linked to the one in `by`, returning at most `limit` results starting
+    /// from `offset`.
+    ///
+    /// MusicBrainz caps `limit` at 100.
     #[tracing::instrument(skip(client))]
-    #[allow(unused)]
     async fn browse(
-        client: &mut Client,
-        related: String,
+        client: &Client,
+        by: &Self::BrowseBy,
         limit: usize,
         offset: usize,
-    ) -> Result<Self, MusicBrainzError> {
-        todo!()
-    }
-}
+    ) -> Result<Page<Self>, MusicBrainzError> {
+        let (linked, mbid) = by.linked();
+        let mut browse_url = client
+            .ws_url(Self::NAME)
+            .map_err(MusicBrainzError::BrowseParseUrl)?;
+        browse_url
+            .query_pairs_mut()
+            .append_pair(linked, &mbid.to_string())
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+        tracing::debug!(%browse_url);
 
-#[derive(Clone)]
-struct MusicBrainzRetry(usize);
+        let text = client
+            .get_text(browse_url)
+            .await
+            .map_err(|e| e.or_not_found(mbid))?;
+        tracing::trace!(text);
 
-impl MusicBrainzRetry {
-    fn subtract(&self) -> std::future::Ready<Self> {
-        std::future::ready(Self(self.0 - 1))
+        client.parse_page(
+            &text,
+            &format!("{}-count", Self::NAME),
+            &format!("{}-offset", Self::NAME),
+            Self::PLURAL,
+            MusicBrainzError::BrowseParseResponse,
+        )
     }
 }
 
-impl<E: std::fmt::Debug> tower::retry::Policy<Request, Response, E> for MusicBrainzRetry {
-    type Future = std::future::Ready<Self>;
-
-    fn retry(&self, _: &Request, result: Result<&Response, &E>) -> Option<Self::Future> {
-        match result {
-            Ok(response) => {
-                match response.status().as_u16() {
-                    // retry on timeout or throttle
-                    408 | 429 => Some(self.subtract()),
-                    // don't retry otherwise
-                    _ => None,
-                }
-            }
-            Err(err) => {
-                tracing::warn!(?err);
-                if self.0 > 0 {
-                    tracing::warn!("Retrying, {} attempts remain.", self.0);
-                    Some(std::future::ready(MusicBrainzRetry(self.0 - 1)))
-                } else {
-                    None
-                }
-            }
-        }
-    }
+/// The user agent sent by default. MusicBrainz asks applications to identify themselves, so
+/// anything built on this crate should set its own with [`ClientBuilder::user_agent`].
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "musicbrainz-rs/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/lovesegfault/malt)"
+);
 
-    fn clone_request(&self, req: &Request) -> Option<Request> {
-        req.try_clone()
-    }
-}
+/// The type-erased service every [`Client`] request goes through.
+pub type ClientService = BoxService<Request, Response, BoxError>;
 
-pub struct Client {
-    svc: BoxService<Request, Response, Arc<dyn Error + Send + Sync>>,
+/// The configuration of a [`Client`], set through [`ClientBuilder`].
+#[derive(Debug, Clone, derive_builder::Builder)]
+#[builder(
+    name = "ClientBuilder",
+    public,
+    build_fn(private, name = "build_config")
+)]
+struct ClientConfig {
+    /// The MusicBrainz server to query, e.g. a local mirror. Defaults to
+    /// `https://musicbrainz.org/`.
+    #[builder(
+        default = r#"reqwest::Url::parse("https://musicbrainz.org/").expect("valid base url")"#
+    )]
+    base_url: reqwest::Url,
+    /// The `User-Agent` to identify as, see
+    /// [the rules](https://musicbrainz.org/doc/MusicBrainz_API/Rate_Limiting#Provide_meaningful_User-Agent_strings).
+    /// Defaults to [`DEFAULT_USER_AGENT`].
+    #[builder(setter(into), default = "DEFAULT_USER_AGENT.to_string()")]
+    user_agent: String,
+    /// At most how many requests to send per period of time, or `None` for no limit. Defaults to
+    /// one request per second, as required by `musicbrainz.org`.
+    #[builder(default = "Some((1, Duration::from_secs(1)))")]
+    rate_limit: Option<(u64, Duration)>,
+    /// How long to wait for each request before giving up on it. Defaults to 10s.
+    #[builder(default = "Duration::from_secs(10)")]
+    timeout: Duration,
+    /// How to retry failed requests. Defaults to [`MusicBrainzRetry::default`].
+    #[builder(default)]
+    retry: MusicBrainzRetry,
+    /// How many requests can be queued waiting for the rate limit. Defaults to 100.
+    #[builder(default = "100")]
+    buffer: usize,
+    /// A persistent cache of responses. Requests are only rate limited when they miss it.
+    #[builder(setter(strip_option), default)]
+    cache: Option<Cache>,
+    /// Whether to fail on responses which don't exactly match our types, reporting the path of
+    /// every unmodelled field with [`MusicBrainzError::Strict`], rather than silently ignoring
+    /// them or keeping them in an `extra` map. Useful to audit the types against
+    /// live data. Defaults to `false`.
+    #[builder(default)]
+    strict: bool,
+    /// Extra middleware wrapped around every request, see [`ClientBuilder::layer`].
+    #[builder(setter(custom), default)]
+    layers: Vec<BoxLayer<ClientService, Request, Response, BoxError>>,
 }
 
-impl Client {
-    pub fn new() -> Result<Self, MusicBrainzError> {
+impl ClientBuilder {
+    /// Adds a middleware layer, e.g. for metrics, logging or authentication.
+    ///
+    /// Layers see each request once, before it is looked up in the cache, rate limited, retried or
+    /// timed out, and the first layer added is the outermost one.
+    pub fn layer<L>(&mut self, layer: L) -> &mut Self
+    where
+        L: Layer<ClientService> + Send + Sync + 'static,
+        L::Service: Service<Request, Response = Response, Error = BoxError> + Send + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        self.layers
+            .get_or_insert_with(Vec::new)
+            .push(BoxLayer::new(layer));
+        self
+    }
+
+    /// Creates a [`Client`] with the options set so far.
+    pub fn build(&self) -> Result<Client, MusicBrainzError> {
+        let config = self
+            .build_config()
+            .expect("every client option has a default");
+
         let mut headers = reqwest::header::HeaderMap::new();
         headers.insert(
             "Accept",
             reqwest::header::HeaderValue::from_static("application/json"),
         );
         let client = reqwest::ClientBuilder::new()
-            .user_agent("musicbrainz-rs/0.0.0 (https://github.com/lovesegfault/musicbrainz-rs)")
+            .user_agent(&config.user_agent)
             .default_headers(headers)
             .build()
             .map_err(MusicBrainzError::ClientCreate)?;
-        Ok(Self::from(client))
+        Ok(Client::with_config(
+            BoxCloneService::new(client.map_err(Into::into)),
+            config,
+        ))
+    }
+
+    /// Creates a [`Client`] which sends its requests through `transport` rather than a
+    /// `reqwest::Client`, e.g. a fake server in tests.
+    ///
+    /// The transport is responsible for the `User-Agent` and `Accept: application/json` headers,
+    /// so [`user_agent`](Self::user_agent) is ignored.
+    pub fn build_with_transport<S>(&self, transport: S) -> Client
+    where
+        S: Service<Request, Response = Response> + Clone + Send + 'static,
+        S::Error: Into<BoxError>,
+        S::Future: Send + 'static,
+    {
+        let config = self
+            .build_config()
+            .expect("every client option has a default");
+        Client::with_config(BoxCloneService::new(transport.map_err(Into::into)), config)
     }
+}
 
-    async fn get(&mut self, url: reqwest::Url) -> Result<Response, MusicBrainzError> {
-        self.svc
-            .ready()
+/// A MusicBrainz web service client.
+///
+/// Clients are cheap to clone, and all clones share the same queue of requests, and so the same
+/// rate limit, which makes it safe to use them from several tasks at once.
+#[derive(Clone)]
+pub struct Client {
+    svc: Buffer<BoxService<Request, Response, BoxError>, Request>,
+    base_url: reqwest::Url,
+    strict: bool,
+}
+
+impl Client {
+    /// Creates a client for `musicbrainz.org` with the default options, see [`ClientBuilder`] to
+    /// change them.
+    pub fn new() -> Result<Self, MusicBrainzError> {
+        Self::builder().build()
+    }
+
+    /// Starts configuring a client, e.g. to query a local mirror or to set a user agent.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    fn with_config(
+        transport: BoxCloneService<Request, Response, BoxError>,
+        config: ClientConfig,
+    ) -> Self {
+        let svc = tower::ServiceBuilder::new()
+            .timeout(config.timeout)
+            .service(transport);
+        // The limiter sits below the retries, so that every attempt waits for its turn. It is
+        // buffered as retrying needs a service it can clone, and clones must share the limit.
+        let svc = match config.rate_limit {
+            Some((num, per)) => BoxCloneService::new(Buffer::new(
+                tower::limit::RateLimit::new(svc, tower::limit::rate::Rate::new(num, per)),
+                config.buffer,
+            )),
+            None => BoxCloneService::new(svc),
+        };
+        let svc = tower::ServiceBuilder::new()
+            .retry(config.retry)
+            .service(svc)
+            .boxed();
+        let svc = match config.cache {
+            Some(cache) => BoxService::new(cache.buffer(config.buffer).layer(svc)),
+            None => svc,
+        };
+        let svc = config
+            .layers
+            .iter()
+            .rev()
+            .fold(svc, |svc, layer| layer.layer(svc));
+        let svc = Buffer::new(svc, config.buffer);
+
+        // A base URL without a trailing slash would have its last segment replaced when joined
+        let mut base_url = config.base_url;
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        Self {
+            svc,
+            base_url,
+            strict: config.strict,
+        }
+    }
+
+    /// Builds the URL of a web service resource, e.g. `release/<mbid>`.
+    fn ws_url(&self, resource: &str) -> Result<reqwest::Url, ::url::ParseError> {
+        self.base_url.join(&format!("ws/2/{}", resource))
+    }
+
+    async fn get(&self, url: reqwest::Url) -> Result<Response, MusicBrainzError> {
+        // Clones of a buffer share its worker, so this only clones a handle to the queue
+        let mut svc = self.svc.clone();
+        svc.ready()
             .await
-            .map_err(MusicBrainzError::ClientReady)?
+            .map_err(|e| MusicBrainzError::ClientReady(Arc::from(e)))?
             .call(Request::new(Method::GET, url))
             .await
-            .map_err(MusicBrainzError::ClientGet)
+            .map_err(|e| {
+                let e = match e.downcast::<retry::Permanent>() {
+                    Ok(e) => e.0,
+                    Err(e) => e,
+                };
+                match e.downcast::<cache::NotCached>() {
+                    Ok(e) => MusicBrainzError::NotCached(e.0),
+                    Err(e) => match e.downcast::<replay::NotRecorded>() {
+                        Ok(e) => MusicBrainzError::NotRecorded(e.0),
+                        Err(e) => MusicBrainzError::ClientGet(Arc::from(e)),
+                    },
+                }
+            })
+    }
+
+    /// Parses a page of results, strictly if the client is.
+    fn parse_page<T>(
+        &self,
+        text: &str,
+        count_key: &str,
+        offset_key: &str,
+        items_key: &str,
+        parse_error: fn(serde_json::Error) -> MusicBrainzError,
+    ) -> Result<Page<T>, MusicBrainzError>
+    where
+        for<'de> T: Deserialize<'de> + Serialize,
+    {
+        if self.strict {
+            Page::from_json_strict(text, count_key, offset_key, items_key)
+                .map_err(MusicBrainzError::Strict)
+        } else {
+            Page::from_json(text, count_key, offset_key, items_key).map_err(parse_error)
+        }
+    }
+
+    /// GETs `url`, returning the response body if it was successful.
+    async fn get_text(&self, url: reqwest::Url) -> Result<String, MusicBrainzError> {
+        let res = self.get(url).await?;
+        tracing::debug!(?res);
+
+        let status = res.status();
+        let headers = res.headers().clone();
+        let text = res.text().await.map_err(MusicBrainzError::Body)?;
+        if status.is_success() {
+            Ok(text)
+        } else {
+            Err(MusicBrainzError::from_response(status, &headers, &text))
+        }
     }
 
-    pub async fn lookup<E: Entity>(&mut self, mbid: &Mbid) -> Result<E, MusicBrainzError> {
+    pub async fn lookup<E: Entity>(&self, mbid: &Mbid) -> Result<E, MusicBrainzError> {
         E::lookup(self, mbid).await
     }
+
+    pub async fn lookup_with<E: Entity>(
+        &self,
+        mbid: &Mbid,
+        includes: &[E::Include],
+    ) -> Result<E, MusicBrainzError> {
+        E::lookup_with(self, mbid, includes).await
+    }
+
+    pub async fn search<E: Entity>(
+        &self,
+        query: QueryString,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Page<SearchResult<E>>, MusicBrainzError> {
+        E::search(self, query, limit, offset).await
+    }
+
+    pub async fn browse<E: Entity>(
+        &self,
+        by: &E::BrowseBy,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Page<E>, MusicBrainzError> {
+        E::browse(self, by, limit, offset).await
+    }
+
+    /// Streams every result of a search, fetching pages of up to 100 items as needed.
+    ///
+    /// At most `max` results are returned, if given.
+    pub fn search_all<E: Entity>(
+        &self,
+        query: QueryString,
+        max: Option<usize>,
+    ) -> impl Stream<Item = Result<SearchResult<E>, MusicBrainzError>> + '_ {
+        page::paginate(self, max, move |client, limit, offset| {
+            E::search(client, query.clone(), limit, offset)
+        })
+    }
+
+    /// Streams every entity linked to the one in `by`, fetching pages of up to 100 items as
+    /// needed.
+    ///
+    /// At most `max` entities are returned, if given.
+    pub fn browse_all<E: Entity>(
+        &self,
+        by: E::BrowseBy,
+        max: Option<usize>,
+    ) -> impl Stream<Item = Result<E, MusicBrainzError>> + '_ {
+        page::paginate(self, max, move |client, limit, offset| {
+            let by = by.clone();
+            Box::pin(async move { E::browse(client, &by, limit, offset).await })
+        })
+    }
 }
 
 impl From<reqwest::Client> for Client {
+    /// Wraps an existing `reqwest` client, using the default options for everything else. The
+    /// client should send `Accept: application/json`.
     fn from(client: reqwest::Client) -> Self {
-        let svc = tower::ServiceBuilder::new()
-            .buffer(100)
-            .rate_limit(1, std::time::Duration::from_secs(1))
-            .retry(MusicBrainzRetry(5))
-            .timeout(std::time::Duration::from_secs(10))
-            .service(client)
-            .map_err(Arc::<dyn Error + Send + Sync>::from)
-            .boxed();
-        Self { svc }
+        ClientBuilder::default().build_with_transport(client)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::mock::mount_json;
+    use futures::TryStreamExt;
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/fixtures/replay");
+
+    /// The MBIDs listed in one of the `assets`, e.g. `releases.txt`.
+    fn asset_mbids(name: &str) -> Vec<Mbid> {
+        let path = format!("{}/assets/{}", env!("CARGO_MANIFEST_DIR"), name);
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| line.parse().unwrap())
+            .collect()
+    }
+
+    /// Looks up every entity in `asset` from its recorded fixture, failing if any is missing.
+    async fn replay_lookups<E: Entity>(asset: &str) {
+        let client = Client::builder()
+            .rate_limit(None)
+            .build_with_transport(replay::Replay::new(FIXTURES));
+        let mbids = asset_mbids(asset);
+        assert!(!mbids.is_empty(), "No MBIDs listed in {}", asset);
+        for mbid in mbids {
+            if let Err(e) = client.lookup::<E>(&mbid).await {
+                panic!(
+                    "Failed to replay {} {}, record it with `cargo test -- --ignored record_fixtures`: {:?}",
+                    E::NAME,
+                    mbid,
+                    e
+                );
+            }
+        }
+    }
+
+    // The MBIDs with a committed fixture are listed next to them, a subset of the full lists until
+    // every fixture is recorded.
+    #[tokio::test]
+    async fn replay_releases() {
+        replay_lookups::<Release>("fixtures/replay/releases.txt").await;
+    }
+
+    #[tokio::test]
+    async fn replay_release_groups() {
+        replay_lookups::<ReleaseGroup>("fixtures/replay/release-groups.txt").await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs every fixture recorded by record_fixtures"]
+    async fn replay_all() {
+        replay_lookups::<Release>("releases.txt").await;
+        replay_lookups::<ReleaseGroup>("release-groups.txt").await;
+    }
+
+    /// Records the fixtures replayed by the tests above from `musicbrainz.org`, run with
+    /// `cargo test -- --ignored record_fixtures`, and lists them. Fixtures which already exist are
+    /// kept.
+    #[tokio::test]
+    #[ignore = "records fixtures from musicbrainz.org"]
+    async fn record_fixtures() {
+        async fn record<E: Entity>(client: &Client, asset: &str) {
+            let mut recorded = String::new();
+            for mbid in asset_mbids(asset) {
+                client.lookup::<E>(&mbid).await.unwrap();
+                recorded.push_str(&format!("{}\n", mbid));
+            }
+            std::fs::write(format!("{}/{}", FIXTURES, asset), recorded).unwrap();
+        }
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            "Accept",
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+        let transport = reqwest::ClientBuilder::new()
+            .user_agent(DEFAULT_USER_AGENT)
+            .default_headers(headers)
+            .build()
+            .unwrap();
+        let client =
+            Client::builder().build_with_transport(replay::Replay::new(FIXTURES).record(transport));
+
+        record::<Release>(&client, "releases.txt").await;
+        record::<ReleaseGroup>(&client, "release-groups.txt").await;
+    }
+
+    #[tokio::test]
+    async fn search_releases() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/release"))
+            .and(query_param("query", "release:Lateralus AND country:US"))
+            .and(query_param("limit", "2"))
+            .and(query_param("offset", "4"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{
+                    "created": "2022-07-24T18:44:49.427Z",
+                    "count": 5,
+                    "offset": 4,
+                    "releases": [{
+                        "id": "2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                        "score": 100,
+                        "status-id": "4e304316-386d-3409-af2e-78857eec5cfe",
+                        "count": 1,
+                        "title": "Lateralus",
+                        "status": "Official",
+                        "text-representation": {"language": "eng", "script": "Latn"},
+                        "artist-credit": [{
+                            "name": "Tool",
+                            "artist": {
+                                "id": "66fc5bf8-daa4-4241-b378-9bc9077939d2",
+                                "name": "Tool",
+                                "sort-name": "Tool"
+                            }
+                        }],
+                        "release-group": {
+                            "id": "7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93",
+                            "type-id": "f529b476-6e62-324f-b0aa-1f3e33d313fc",
+                            "primary-type-id": "f529b476-6e62-324f-b0aa-1f3e33d313fc",
+                            "title": "Lateralus",
+                            "primary-type": "Album"
+                        },
+                        "date": "2001-05-15",
+                        "country": "US",
+                        "release-events": [{"date": "2001-05-15"}],
+                        "barcode": "614223116022",
+                        "track-count": 13
+                    }]
+                }"#,
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = mock::client(&server);
+        let page: Page<SearchResult<Release>> = client
+            .search(QueryString("release:Lateralus AND country:US".into()), 2, 4)
+            .await
+            .unwrap();
+
+        assert_eq!(page.count, 5);
+        assert_eq!(page.offset, 4);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].score, 100);
+        assert_eq!(page.items[0].entity.title, "Lateralus");
+        assert_eq!(
+            page.items[0].entity.barcode.as_deref(),
+            Some("614223116022")
+        );
+        assert_eq!(
+            page.items[0].entity.release_group.as_ref().unwrap().title,
+            "Lateralus"
+        );
+        assert_eq!(
+            page.items[0]
+                .entity
+                .artist_credit
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            "Tool"
+        );
+    }
+
+    #[tokio::test]
+    async fn search_release_groups() {
+        let server = MockServer::start().await;
+        mount_json(
+            &server,
+            "release-group",
+            r#"{
+                "created": "2022-07-24T18:44:49.427Z",
+                "count": 1,
+                "offset": 0,
+                "release-groups": [{
+                    "id": "7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93",
+                    "type-id": "f529b476-6e62-324f-b0aa-1f3e33d313fc",
+                    "score": 87,
+                    "primary-type-id": "f529b476-6e62-324f-b0aa-1f3e33d313fc",
+                    "count": 12,
+                    "title": "Lateralus",
+                    "first-release-date": "2001-05-15",
+                    "primary-type": "Album"
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let page = ReleaseGroup::search(&client, QueryString("Lateralus".into()), 25, 0)
+            .await
+            .unwrap();
+
+        assert_eq!(page.count, 1);
+        assert_eq!(page.items[0].score, 87);
+        assert_eq!(
+            page.items[0].entity.first_release_date.to_string(),
+            "2001-05-15"
+        );
+        assert!(page.items[0].entity.secondary_types.is_empty());
+    }
+
+    #[tokio::test]
+    async fn search_areas() {
+        let server = MockServer::start().await;
+        mount_json(
+            &server,
+            "area",
+            r#"{
+                "created": "2022-07-24T18:44:49.427Z",
+                "count": 1,
+                "offset": 0,
+                "areas": [{
+                    "id": "489ce91b-6658-3307-9877-795b68554c98",
+                    "type": "Country",
+                    "type-id": "06dd0ae4-8c74-30bb-b43d-95dcedf961de",
+                    "score": 100,
+                    "name": "United States",
+                    "sort-name": "United States",
+                    "iso-3166-1-codes": ["US"],
+                    "life-span": {"ended": null}
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let page = Area::search(&client, QueryString("area:US".into()), 25, 0)
+            .await
+            .unwrap();
+
+        assert_eq!(page.items[0].entity.name, "United States");
+        assert_eq!(page.items[0].entity.iso_3166_1_codes, vec!["US"]);
+    }
+
+    #[tokio::test]
+    async fn browse_releases_by_release_group() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/release"))
+            .and(query_param(
+                "release-group",
+                "7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93",
+            ))
+            .and(query_param("limit", "100"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{
+                    "release-count": 2,
+                    "release-offset": 0,
+                    "releases": [{
+                        "id": "2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                        "title": "Lateralus",
+                        "status": "Official",
+                        "quality": "normal",
+                        "disambiguation": "",
+                        "packaging": "Jewel Case",
+                        "cover-art-archive": {
+                            "front": true, "artwork": true, "count": 4, "darkened": false, "back": true
+                        },
+                        "text-representation": {"language": "eng", "script": "Latn"},
+                        "country": "US",
+                        "barcode": "614223116022"
+                    }, {
+                        "id": "9b1acd78-3d19-4a2b-a7b4-a6f24c9f14e4",
+                        "title": "Lateralus",
+                        "status": "Official",
+                        "quality": "normal",
+                        "disambiguation": "picture disc",
+                        "text-representation": {"language": "eng", "script": "Latn"},
+                        "country": "US",
+                        "barcode": null
+                    }]
+                }"#,
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = mock::client(&server);
+        let by = release::ReleaseBrowseBy::ReleaseGroup(
+            "7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93".parse().unwrap(),
+        );
+        let page: Page<Release> = client.browse(&by, 100, 0).await.unwrap();
+
+        assert_eq!(page.count, 2);
+        assert_eq!(page.offset, 0);
+        assert_eq!(page.items[1].disambiguation, "picture disc");
+        assert!(page.items[0].cover_art_archive.as_ref().unwrap().front);
+    }
+
+    fn area_page(offset: usize, names: &[&str]) -> String {
+        let areas = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                format!(
+                    r#"{{"id": "00000000-0000-0000-0000-00000000000{}", "score": 100, "name": "{}", "sort-name": "{}"}}"#,
+                    offset + i,
+                    name,
+                    name
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            r#"{{"count": 3, "offset": {}, "areas": [{}]}}"#,
+            offset, areas
+        )
+    }
+
+    #[tokio::test]
+    async fn search_all_pages() {
+        let server = MockServer::start().await;
+        for (offset, names) in [(0, &["Lisbon", "Porto"][..]), (2, &["Braga"][..])] {
+            Mock::given(method("GET"))
+                .and(path("/ws/2/area"))
+                .and(query_param("limit", "100"))
+                .and(query_param("offset", offset.to_string()))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_raw(area_page(offset, names), "application/json"),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = mock::client(&server);
+        let areas: Vec<SearchResult<Area>> = client
+            .search_all(QueryString("country:PT".into()), None)
+            .try_collect()
+            .await
+            .unwrap();
+
+        let names: Vec<_> = areas.iter().map(|a| a.entity.name.as_str()).collect();
+        assert_eq!(names, ["Lisbon", "Porto", "Braga"]);
+    }
+
+    #[tokio::test]
+    async fn search_all_max() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/area"))
+            .and(query_param("limit", "2"))
+            .and(query_param("offset", "0"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(area_page(0, &["Lisbon", "Porto"]), "application/json"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock::client(&server);
+        let areas: Vec<SearchResult<Area>> = client
+            .search_all(QueryString("country:PT".into()), Some(2))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(areas.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn lookup_with_includes() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/ws/2/release-group/7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93",
+            ))
+            .and(query_param("inc", "releases discids"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{
+                    "id": "7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93",
+                    "title": "Lateralus",
+                    "first-release-date": "2001-05-15",
+                    "primary-type": "Album",
+                    "primary-type-id": "f529b476-6e62-324f-b0aa-1f3e33d313fc",
+                    "secondary-types": [],
+                    "secondary-type-ids": [],
+                    "disambiguation": "",
+                    "releases": [{
+                        "id": "2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                        "title": "Lateralus",
+                        "status": "Official",
+                        "quality": "normal",
+                        "disambiguation": "",
+                        "text-representation": {"language": "eng", "script": "Latn"},
+                        "release-events": [{"date": "2001-05-15", "area": null}]
+                    }]
+                }"#,
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = mock::client(&server);
+        let release_group: ReleaseGroup = client
+            .lookup_with(
+                &"7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93".parse().unwrap(),
+                &[
+                    release_group::ReleaseGroupInc::Releases,
+                    release_group::ReleaseGroupInc::DiscIds,
+                ],
+            )
+            .await
+            .unwrap();
+
+        let releases = release_group.releases.unwrap();
+        assert_eq!(releases.len(), 1);
+        assert_eq!(releases[0].title, "Lateralus");
+    }
+
+    #[tokio::test]
+    async fn lookup_relations() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/artist/66fc5bf8-daa4-4241-b378-9bc9077939d2"))
+            .and(query_param("inc", "artist-rels series-rels url-rels"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{
+                    "id": "66fc5bf8-daa4-4241-b378-9bc9077939d2",
+                    "name": "Tool",
+                    "sort-name": "Tool",
+                    "relations": [{
+                        "type": "member of band",
+                        "type-id": "5be4c609-9afa-4ea0-910b-12ffb71e3821",
+                        "direction": "backward",
+                        "target-type": "artist",
+                        "attributes": ["lead vocals"],
+                        "attribute-values": {},
+                        "attribute-ids": {"lead vocals": "8e2a3255-87c2-4809-a174-98cb3704f1a5"},
+                        "begin": "1990",
+                        "end": null,
+                        "ended": false,
+                        "source-credit": "",
+                        "target-credit": "",
+                        "artist": {
+                            "id": "c5f3cf2f-0b51-4e2e-b3c4-a8ec3e6e5a0f",
+                            "name": "Maynard James Keenan",
+                            "sort-name": "Keenan, Maynard James",
+                            "type": "Person"
+                        }
+                    }, {
+                        "type": "part of",
+                        "type-id": "5ee5a1c9-5d6c-4a8b-a2f2-7d4a6e8c4b2e",
+                        "direction": "forward",
+                        "target-type": "series",
+                        "attributes": ["number"],
+                        "attribute-values": {"number": "3"},
+                        "attribute-ids": {"number": "a59c5830-5ec7-38fe-9a21-c7ea54f6650a"},
+                        "ordering-key": 3,
+                        "begin": null,
+                        "end": null,
+                        "ended": false,
+                        "source-credit": "",
+                        "target-credit": "",
+                        "series": {
+                            "id": "9f6c5a2b-0e5d-4e3a-9c1b-8a4f7e6d5c3b",
+                            "name": "Lollapalooza Headliners",
+                            "type": "Artist series"
+                        }
+                    }, {
+                        "type": "wikidata",
+                        "type-id": "689870a4-a1e4-4912-b17f-7b2664215698",
+                        "direction": "forward",
+                        "target-type": "url",
+                        "attributes": [],
+                        "attribute-values": {},
+                        "attribute-ids": {},
+                        "begin": null,
+                        "end": null,
+                        "ended": false,
+                        "source-credit": "",
+                        "target-credit": "",
+                        "url": {
+                            "id": "0e4b8d4e-8a8e-4f64-a7d5-5e9f7a3c2b1d",
+                            "resource": "https://www.wikidata.org/wiki/Q184225"
+                        }
+                    }, {
+                        "type": "some future link",
+                        "type-id": "0d5e6f7a-8b9c-4d0e-9f1a-2b3c4d5e6f7a",
+                        "direction": "forward",
+                        "target-type": "hologram",
+                        "hologram": {"id": "1e2d3c4b-5a69-4877-8695-a4b3c2d1e0f9"}
+                    }]
+                }"#,
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = mock::client(&server);
+        let artist: Artist = client
+            .lookup_with(
+                &"66fc5bf8-daa4-4241-b378-9bc9077939d2".parse().unwrap(),
+                &[
+                    artist::ArtistInc::ArtistRels,
+                    artist::ArtistInc::SeriesRels,
+                    artist::ArtistInc::UrlRels,
+                ],
+            )
+            .await
+            .unwrap();
+
+        let relations = artist.relations.unwrap();
+        assert_eq!(relations.len(), 4);
+
+        let member = &relations[0];
+        assert_eq!(member.attributes, ["lead vocals"]);
+        match &member.target {
+            Some(relationship::RelationTarget::Artist(artist)) => {
+                assert_eq!(artist.name, "Maynard James Keenan")
+            }
+            target => panic!("unexpected relation target {:?}", target),
+        }
+
+        let series = &relations[1];
+        assert_eq!(series.ordering_key, Some(3));
+        assert_eq!(series.attribute_values["number"], "3");
+
+        let wikidata = relations.iter().find(|r| r.r_type == "wikidata").unwrap();
+        match &wikidata.target {
+            Some(relationship::RelationTarget::Url(url)) => {
+                assert_eq!(url.resource, "https://www.wikidata.org/wiki/Q184225")
+            }
+            target => panic!("unexpected relation target {:?}", target),
+        }
+        assert_eq!(
+            wikidata.target.as_ref().unwrap().id().unwrap().to_string(),
+            "0e4b8d4e-8a8e-4f64-a7d5-5e9f7a3c2b1d"
+        );
+
+        assert_eq!(relations[3].target_type, "hologram");
+        match &relations[3].target {
+            Some(relationship::RelationTarget::Unknown { target_type, value }) => {
+                assert_eq!(target_type, "hologram");
+                assert_eq!(value["id"], "1e2d3c4b-5a69-4877-8695-a4b3c2d1e0f9");
+            }
+            target => panic!("unexpected relation target {:?}", target),
+        }
+    }
+
+    #[tokio::test]
+    async fn lookup_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/artist/66fc5bf8-daa4-4241-b378-9bc9077939d2"))
+            .respond_with(ResponseTemplate::new(404).set_body_raw(
+                r#"{"error": "Not Found", "help": "For usage, please see: https://musicbrainz.org/development/mmd"}"#,
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = mock::client(&server);
+        let mbid: Mbid = "66fc5bf8-daa4-4241-b378-9bc9077939d2".parse().unwrap();
+        let err = client.lookup::<Artist>(&mbid).await.unwrap_err();
+        assert!(
+            matches!(err, MusicBrainzError::NotFound(m) if m == mbid),
+            "{:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn search_http_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/release"))
+            .respond_with(ResponseTemplate::new(400).set_body_raw(
+                r#"{"error": "Invalid search query", "help": "For usage, please see: https://musicbrainz.org/development/mmd"}"#,
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = mock::client(&server);
+        let err = client
+            .search::<Release>(QueryString("release:(".into()), 1, 0)
+            .await
+            .unwrap_err();
+        match err {
+            MusicBrainzError::Http { status, message } => {
+                assert_eq!(status, StatusCode::BAD_REQUEST);
+                assert_eq!(message, "Invalid search query");
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
     #[test]
-    fn it_works() {
-        let result = 2 + 2;
-        assert_eq!(result, 4);
+    fn unavailable_errors() {
+        let mut headers = HeaderMap::new();
+        let err = MusicBrainzError::from_response(StatusCode::SERVICE_UNAVAILABLE, &headers, "");
+        assert!(
+            matches!(err, MusicBrainzError::ServiceUnavailable),
+            "{:?}",
+            err
+        );
+
+        headers.insert(reqwest::header::RETRY_AFTER, "2".parse().unwrap());
+        let err = MusicBrainzError::from_response(StatusCode::SERVICE_UNAVAILABLE, &headers, "");
+        assert!(
+            matches!(
+                err,
+                MusicBrainzError::RateLimited {
+                    retry_after: Some(d)
+                } if d == Duration::from_secs(2)
+            ),
+            "{:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn lookup_retries_when_throttled() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18"))
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "0"))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        mount_json(
+            &server,
+            "genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18",
+            r#"{"id": "ceeaa283-5d7b-4202-8d1d-e25d116b2a18", "name": "alternative rock"}"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+        let genre: Genre = client.lookup(&mbid).await.unwrap();
+        assert_eq!(genre.name, "alternative rock");
+    }
+
+    #[tokio::test]
+    async fn retries_are_rate_limited() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18"))
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "0"))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        mount_json(
+            &server,
+            "genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18",
+            r#"{"id": "ceeaa283-5d7b-4202-8d1d-e25d116b2a18", "name": "alternative rock"}"#,
+        )
+        .await;
+
+        let client = Client::builder()
+            .base_url(reqwest::Url::parse(&server.uri()).unwrap())
+            .rate_limit(Some((1, Duration::from_millis(200))))
+            .build()
+            .unwrap();
+        let mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+        let start = std::time::Instant::now();
+        let genre: Genre = client.lookup(&mbid).await.unwrap();
+        assert_eq!(genre.name, "alternative rock");
+        // Retrying immediately as asked, three attempts still take two periods of the limit
+        assert!(start.elapsed() >= Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn builder_options() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/mirror/ws/2/genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18",
+            ))
+            .and(header("User-Agent", "malt-tests/1.0 (malt@example.com)"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{"id": "ceeaa283-5d7b-4202-8d1d-e25d116b2a18", "name": "alternative rock"}"#,
+                "application/json",
+            ))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .base_url(reqwest::Url::parse(&format!("{}/mirror", server.uri())).unwrap())
+            .user_agent("malt-tests/1.0 (malt@example.com)")
+            .rate_limit(None)
+            .timeout(std::time::Duration::from_secs(1))
+            .retry(MusicBrainzRetry::new(0))
+            .build()
+            .unwrap();
+        let mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+        let start = std::time::Instant::now();
+        for _ in 0..3 {
+            let genre: Genre = client.lookup(&mbid).await.unwrap();
+            assert_eq!(genre.name, "alternative rock");
+        }
+        // With the default rate limit this would take at least two seconds
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn clones_share_rate_limit() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+        assert_shareable::<Client>();
+
+        let server = MockServer::start().await;
+        mount_json(
+            &server,
+            "genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18",
+            r#"{"id": "ceeaa283-5d7b-4202-8d1d-e25d116b2a18", "name": "alternative rock"}"#,
+        )
+        .await;
+
+        let client = Client::builder()
+            .base_url(reqwest::Url::parse(&server.uri()).unwrap())
+            .rate_limit(Some((1, Duration::from_millis(200))))
+            .build()
+            .unwrap();
+        let mbid: Mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+        let start = std::time::Instant::now();
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.lookup::<Genre>(&mbid).await })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap().unwrap().name, "alternative rock");
+        }
+        // Four requests at one per 200ms can't all have been sent within 600ms
+        assert!(start.elapsed() >= Duration::from_millis(600));
+    }
+
+    #[tokio::test]
+    async fn fake_transport_with_layers() {
+        let transport = tower::service_fn(|req: Request| async move {
+            assert_eq!(
+                req.url().as_str(),
+                "https://musicbrainz.org/ws/2/genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18"
+            );
+            assert_eq!(req.headers()["x-layers"], "outer, inner");
+            let res = http::Response::builder()
+                .status(200)
+                .body(
+                    r#"{"id": "ceeaa283-5d7b-4202-8d1d-e25d116b2a18", "name": "alternative rock"}"#,
+                )
+                .unwrap();
+            Ok::<_, BoxError>(Response::from(res))
+        });
+        let tag = |name: &'static str| {
+            tower::util::MapRequestLayer::new(move |mut req: Request| {
+                let value = match req.headers().get("x-layers") {
+                    Some(v) => format!("{}, {}", v.to_str().unwrap(), name),
+                    None => name.to_string(),
+                };
+                req.headers_mut().insert("x-layers", value.parse().unwrap());
+                req
+            })
+        };
+
+        let client = Client::builder()
+            .rate_limit(None)
+            .layer(tag("outer"))
+            .layer(tag("inner"))
+            .build_with_transport(transport);
+        let mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+        let genre: Genre = client.lookup(&mbid).await.unwrap();
+        assert_eq!(genre.name, "alternative rock");
+    }
+
+    #[tokio::test]
+    async fn strict_lookup() {
+        let server = MockServer::start().await;
+        mount_json(
+            &server,
+            "genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18",
+            r#"{"id": "ceeaa283-5d7b-4202-8d1d-e25d116b2a18", "name": "alternative rock",
+                "disambiguation": "", "tags": []}"#,
+        )
+        .await;
+        let mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+
+        let lenient = mock::client(&server);
+        let genre: Genre = lenient.lookup(&mbid).await.unwrap();
+        assert_eq!(genre.name, "alternative rock");
+
+        let strict = Client::builder()
+            .base_url(reqwest::Url::parse(&server.uri()).unwrap())
+            .strict(true)
+            .build()
+            .unwrap();
+        match strict.lookup::<Genre>(&mbid).await.unwrap_err() {
+            MusicBrainzError::Strict(err) => assert_eq!(err.ignored(), ["tags"]),
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn lookup_keeps_unmodelled_fields() {
+        let server = MockServer::start().await;
+        let body = serde_json::json!({
+            "id": "ceeaa283-5d7b-4202-8d1d-e25d116b2a18",
+            "name": "alternative rock",
+            "disambiguation": "",
+            "tags": [{"name": "rock", "count": 3}],
+        });
+        mount_json(
+            &server,
+            "genre/ceeaa283-5d7b-4202-8d1d-e25d116b2a18",
+            &body.to_string(),
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let mbid = "ceeaa283-5d7b-4202-8d1d-e25d116b2a18".parse().unwrap();
+        let genre: Genre = client.lookup(&mbid).await.unwrap();
+        assert_eq!(genre.extra["tags"][0]["name"], "rock");
+        assert_eq!(serde_json::to_value(&genre).unwrap(), body);
     }
 }
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::PartialDate;
+
+/// The period during which an entity existed, e.g. the birth and death of a person, or the
+/// founding and dissolution of a group.
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct LifeSpan {
+    pub begin: Option<PartialDate>,
+    pub end: Option<PartialDate>,
+    /// Whether the entity has ended, even if `end` is unknown.
+    pub ended: Option<bool>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -19,7 +19,7 @@ use uuid::Uuid;
 ///
 /// # See Also
 /// [Upstream documentation](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-#[derive(serde::Deserialize, serde::Serialize, PartialEq, Eq)]
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Mbid(Uuid);
 
 impl std::fmt::Debug for Mbid {
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ArtistCredit, Mbid, Recording};
+
+/// A medium is the actual physical medium the audio content is stored upon, e.g. each CD in a
+/// multi-disc release is a separate medium. Digital releases have media too, even if they are
+/// not physical.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Medium)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Medium {
+    /// The position of the medium in the release, starting at 1.
+    pub position: u32,
+    /// The format of the medium, e.g. "CD" or "12\" Vinyl".
+    pub format: Option<String>,
+    pub format_id: Option<Mbid>,
+    /// The title of the medium, if it has one, e.g. "Bonus Disc".
+    #[serde(default)]
+    pub title: String,
+    pub track_count: u32,
+    /// The number of tracks on earlier media, only present with a tracklist.
+    pub track_offset: Option<u32>,
+    /// The CD TOCs of the medium, see [`ReleaseInc::DiscIds`](crate::release::ReleaseInc::DiscIds).
+    pub discs: Option<Vec<Disc>>,
+    /// A hidden track before the first track, e.g. in the pregap of a CD.
+    pub pregap: Option<Track>,
+    /// The tracklist, see [`ReleaseInc::Recordings`](crate::release::ReleaseInc::Recordings).
+    pub tracks: Option<Vec<Track>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A track is the way a recording is represented on a particular release (or, more exactly, on a
+/// particular medium). Every track has a title and is credited to one or more artists.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Track)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Track {
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    /// The number of the track as printed on the release, e.g. "A1" on a vinyl.
+    pub number: String,
+    /// The position of the track in its medium, starting at 1 (or 0 for a pregap track).
+    pub position: u32,
+    /// The title of the track, which may differ from that of its recording.
+    pub title: String,
+    /// The length of the track in milliseconds, if known.
+    pub length: Option<u64>,
+    pub recording: Option<Recording>,
+    pub artist_credit: Option<ArtistCredit>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A disc ID is the code number which MusicBrainz uses to link a physical CD to a release listing,
+/// calculated from the CD's table of contents.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Disc_ID)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Disc {
+    pub id: String,
+    /// The total length of the disc, in sectors.
+    pub sectors: u64,
+    pub offset_count: u32,
+    /// The start of every track, in sectors.
+    pub offsets: Vec<u64>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,34 @@
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::Client;
+
+/// A client sending its requests to `server`, with the default options.
+pub(crate) fn client(server: &MockServer) -> Client {
+    Client::builder()
+        .base_url(reqwest::Url::parse(&server.uri()).unwrap())
+        .build()
+        .unwrap()
+}
+
+/// Answers requests for a web service `resource`, e.g. `genre/<mbid>`, with `body`.
+pub(crate) async fn mount_json(server: &MockServer, resource: &str, body: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("/ws/2/{}", resource)))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(server)
+        .await;
+}
+
+/// Answers lookups of `resource` with `body`, as long as they include exactly `inc`, e.g.
+/// `aliases tags`.
+pub(crate) async fn mount_lookup(server: &MockServer, resource: &str, inc: &str, body: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("/ws/2/{}", resource)))
+        .and(query_param("inc", inc))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(server)
+        .await;
+}
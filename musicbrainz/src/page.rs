@@ -0,0 +1,232 @@
+use std::{fmt, marker::PhantomData};
+
+use futures::{future::BoxFuture, stream, Stream, TryStreamExt};
+use serde::{
+    de::{self, DeserializeSeed, IgnoredAny, MapAccess, Visitor},
+    Deserialize, Serialize,
+};
+
+use crate::{
+    strict::{self, StrictError},
+    Client, MusicBrainzError,
+};
+
+/// The largest `limit` MusicBrainz accepts for a single request.
+pub const MAX_LIMIT: usize = 100;
+
+/// A single page of results from a paginated request, such as a search.
+///
+/// MusicBrainz never returns more than 100 results per request, the rest have to be fetched by
+/// repeating the request with an increasing `offset`.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct Page<T> {
+    /// Total number of results, across all pages.
+    pub count: usize,
+    /// Offset of the first item of this page in the complete result list.
+    pub offset: usize,
+    /// The results in this page.
+    pub items: Vec<T>,
+}
+
+impl<T> Page<T>
+where
+    for<'de> T: Deserialize<'de>,
+{
+    /// Parses a page out of a JSON response body.
+    ///
+    /// The keys used by MusicBrainz for the envelope vary with the entity and the kind of request,
+    /// so they need to be supplied by the caller, e.g. `count`, `offset` and `releases` for a
+    /// release search.
+    pub(crate) fn from_json(
+        text: &str,
+        count_key: &str,
+        offset_key: &str,
+        items_key: &str,
+    ) -> Result<Self, serde_json::Error> {
+        let mut de = serde_json::Deserializer::from_str(text);
+        let page = PageSeed {
+            count_key,
+            offset_key,
+            items_key,
+            marker: PhantomData,
+        }
+        .deserialize(&mut de)?;
+        de.end()?;
+        Ok(page)
+    }
+
+    /// Parses a page like [`from_json`](Self::from_json), but fails if any field of the items is
+    /// ignored or only kept in an `extra` map. Unknown keys in the envelope itself are still
+    /// allowed.
+    pub(crate) fn from_json_strict(
+        text: &str,
+        count_key: &str,
+        offset_key: &str,
+        items_key: &str,
+    ) -> Result<Self, StrictError>
+    where
+        T: Serialize,
+    {
+        let seed = PageSeed {
+            count_key,
+            offset_key,
+            items_key,
+            marker: PhantomData,
+        };
+        let (page, mut ignored) = strict::from_str_seed(
+            text,
+            seed,
+            |path| matches!(path.strip_prefix(items_key), Some(rest) if rest.starts_with('[')),
+        )?;
+        if let Ok(serde_json::Value::Object(mut envelope)) = serde_json::from_str(text) {
+            if let Some(items) = envelope.remove(items_key) {
+                strict::unmodelled(&items, &page.items, items_key, &mut ignored);
+            }
+        }
+        strict::finish(page, ignored)
+    }
+}
+
+/// Walks through every page of a request, yielding each item in turn.
+///
+/// `fetch` is called with the `limit` and `offset` of every page, until either `count` items have
+/// been seen or `max` items have been yielded. Pages are only requested as the stream is polled,
+/// so dropping it stops the pagination.
+pub(crate) fn paginate<'a, T, F>(
+    client: &'a Client,
+    max: Option<usize>,
+    fetch: F,
+) -> impl Stream<Item = Result<T, MusicBrainzError>> + 'a
+where
+    T: Send + 'a,
+    F: for<'c> FnMut(&'c Client, usize, usize) -> BoxFuture<'c, Result<Page<T>, MusicBrainzError>>
+        + Send
+        + 'a,
+{
+    struct State<'a, F> {
+        client: &'a Client,
+        fetch: F,
+        offset: usize,
+        remaining: Option<usize>,
+        done: bool,
+    }
+
+    let state = State {
+        client,
+        fetch,
+        offset: 0,
+        remaining: max,
+        done: false,
+    };
+
+    stream::try_unfold(state, |mut state| async move {
+        let limit = state.remaining.map_or(MAX_LIMIT, |r| r.min(MAX_LIMIT));
+        if state.done || limit == 0 {
+            return Ok(None);
+        }
+
+        let mut page = (state.fetch)(state.client, limit, state.offset).await?;
+        page.items.truncate(limit);
+
+        state.offset += page.items.len();
+        state.remaining = state.remaining.map(|r| r - page.items.len());
+        state.done = page.items.is_empty() || state.offset >= page.count;
+
+        Ok(Some((stream::iter(page.items.into_iter().map(Ok)), state)))
+    })
+    .try_flatten()
+}
+
+struct PageSeed<'a, T> {
+    count_key: &'a str,
+    offset_key: &'a str,
+    items_key: &'a str,
+    marker: PhantomData<T>,
+}
+
+impl<'de, 'a, T: Deserialize<'de>> DeserializeSeed<'de> for PageSeed<'a, T> {
+    type Value = Page<T>;
+
+    fn deserialize<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, 'a, T: Deserialize<'de>> Visitor<'de> for PageSeed<'a, T> {
+    type Value = Page<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a page of `{}`", self.items_key)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut count = None;
+        let mut offset = None;
+        let mut items = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            if key == self.count_key {
+                count = Some(map.next_value()?);
+            } else if key == self.offset_key {
+                offset = Some(map.next_value()?);
+            } else if key == self.items_key {
+                items = Some(map.next_value()?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+
+        let missing = |key: &str| de::Error::custom(format_args!("missing field `{}`", key));
+        Ok(Page {
+            count: count.ok_or_else(|| missing(self.count_key))?,
+            offset: offset.ok_or_else(|| missing(self.offset_key))?,
+            items: items.ok_or_else(|| missing(self.items_key))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_envelope() {
+        let text = r#"{"created":"2022-07-24T12:00:00.000Z","count":2,"offset":1,"things":[1,2]}"#;
+        let page: Page<u8> = Page::from_json(text, "count", "offset", "things").unwrap();
+        assert_eq!(
+            page,
+            Page {
+                count: 2,
+                offset: 1,
+                items: vec![1, 2]
+            }
+        );
+    }
+
+    #[test]
+    fn parse_missing_items() {
+        let text = r#"{"count":2,"offset":0,"others":[1,2]}"#;
+        let err = Page::<u8>::from_json(text, "count", "offset", "things").unwrap_err();
+        assert!(err.to_string().contains("`things`"));
+    }
+
+    #[test]
+    fn parse_strict() {
+        #[derive(Debug, Deserialize, Serialize)]
+        struct Thing {
+            #[allow(dead_code)]
+            name: String,
+        }
+
+        let text = r#"{"created":"2022-07-24","count":1,"offset":0,"things":[{"name":"a"}]}"#;
+        let page = Page::<Thing>::from_json_strict(text, "count", "offset", "things").unwrap();
+        assert_eq!(page.items.len(), 1);
+
+        let text = r#"{"count":1,"offset":0,"things":[{"name":"a","new":true}]}"#;
+        let err = Page::<Thing>::from_json_strict(text, "count", "offset", "things").unwrap_err();
+        assert_eq!(err.ignored(), ["things[0].new"]);
+    }
+}
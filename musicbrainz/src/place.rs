@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alias::Alias, life_span::LifeSpan, vocabulary::vocabulary, Area, BrowseFilter, Entity, Include,
+    Mbid, Relationship,
+};
+
+/// A place is a building or outdoor area used for performing or producing music, such as a
+/// studio or a venue.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Place)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Place {
+    /// The official name of the place.
+    pub name: String,
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    #[serde(rename = "type")]
+    pub a_type: Option<PlaceType>,
+    pub type_id: Option<Mbid>,
+    /// The address of the place, in the local format.
+    #[serde(default)]
+    pub address: String,
+    /// The area the place is in, usually a city.
+    pub area: Option<Area>,
+    /// The location of the place.
+    pub coordinates: Option<Coordinates>,
+    /// When the place was opened and closed.
+    pub life_span: Option<LifeSpan>,
+    #[serde(default)]
+    pub disambiguation: String,
+    /// See [`PlaceInc::Aliases`].
+    pub aliases: Option<Vec<Alias>>,
+    /// Links to other entities and URLs, see the `*Rels` variants of [`PlaceInc`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+vocabulary! {
+    pub enum PlaceType {
+        /// A place designed for non-live production of music, typically a recording studio.
+        Studio = "Studio",
+        /// A place that has live artistic performances as one of its primary functions, such as a
+        /// concert hall.
+        Venue = "Venue",
+        /// A place mostly designed for outdoor sporting events, typically a stadium.
+        Stadium = "Stadium",
+        /// A large, indoor space designed for sporting events, also commonly used for concerts.
+        IndoorArena = "Indoor arena",
+        /// A place mostly designed for religious worship, such as a church.
+        ReligiousBuilding = "Religious building",
+        /// A school, university or other similar educational institution.
+        EducationalInstitution = "Educational institution",
+        /// A place where physical media, such as vinyl records, are manufactured.
+        PressingPlant = "Pressing plant",
+        /// Anything which does not fit into the above categories.
+        Other = "Other",
+    }
+}
+
+/// [WGS 84](https://en.wikipedia.org/wiki/World_Geodetic_System) coordinates of a [`Place`].
+///
+/// Coordinates are compared bit for bit, so that they can be [`Eq`] like the types containing
+/// them.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl PartialEq for Coordinates {
+    fn eq(&self, other: &Self) -> bool {
+        self.latitude.to_bits() == other.latitude.to_bits()
+            && self.longitude.to_bits() == other.longitude.to_bits()
+            && self.extra == other.extra
+    }
+}
+
+impl Eq for Coordinates {}
+
+/// The entities [`Place`]s can be browsed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceBrowseBy {
+    Area(Mbid),
+    Collection(Mbid),
+}
+
+impl BrowseFilter for PlaceBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match self {
+            PlaceBrowseBy::Area(mbid) => ("area", mbid),
+            PlaceBrowseBy::Collection(mbid) => ("collection", mbid),
+        }
+    }
+}
+
+/// The subqueries that can be included in a [`Place`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PlaceInc {
+    Aliases,
+    Annotation,
+    Tags,
+    Genres,
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
+}
+
+impl Include for PlaceInc {}
+
+impl Entity for Place {
+    const NAME: &'static str = "place";
+    const PLURAL: &'static str = "places";
+    type BrowseBy = PlaceBrowseBy;
+    type Include = PlaceInc;
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::mock;
+
+    #[test]
+    fn coordinates_eq() {
+        let coordinates = |latitude, longitude| Coordinates {
+            latitude,
+            longitude,
+            extra: Default::default(),
+        };
+        assert_eq!(
+            coordinates(51.5321, -0.17805),
+            coordinates(51.5321, -0.17805)
+        );
+        // Unlike with floats, equality is reflexive
+        assert_eq!(coordinates(f64::NAN, 0.0), coordinates(f64::NAN, 0.0));
+        assert_ne!(
+            coordinates(51.5321, -0.17805),
+            coordinates(-0.17805, 51.5321)
+        );
+    }
+
+    #[tokio::test]
+    async fn lookup_place() {
+        let server = MockServer::start().await;
+        mock::mount_json(
+            &server,
+            "place/bea135c0-a32e-49be-85fd-9234c73fa0a8",
+            r#"{
+                "id": "bea135c0-a32e-49be-85fd-9234c73fa0a8",
+                "name": "Abbey Road Studios",
+                "type": "Studio",
+                "type-id": "05fa6a09-ccd6-3d4a-b1a3-7a3ae2a9a9f8",
+                "address": "3 Abbey Road, St John's Wood, London, NW8 9AY",
+                "area": {
+                    "id": "f03d09b3-39dc-4083-afd6-159e3f0d462f",
+                    "name": "London",
+                    "sort-name": "London",
+                    "disambiguation": ""
+                },
+                "coordinates": {"latitude": 51.5321, "longitude": -0.17805},
+                "life-span": {"begin": "1931-11", "end": null, "ended": false},
+                "disambiguation": ""
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let place: Place = client
+            .lookup(&"bea135c0-a32e-49be-85fd-9234c73fa0a8".parse().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(place.a_type, Some(PlaceType::Studio));
+        assert_eq!(place.area.unwrap().name, "London");
+        let coordinates = place.coordinates.unwrap();
+        assert_eq!(coordinates.latitude, 51.5321);
+        assert_eq!(coordinates.longitude, -0.17805);
+    }
+}
@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    index::{Document, Indexed},
+    ArtistCredit, BrowseFilter, Entity, Include, Mbid, PartialDate, Relationship, Release,
+};
+
+/// A recording is an entity in MusicBrainz which can be linked to tracks on releases. Each track
+/// must always be associated with a single recording, but a recording can be linked to any number
+/// of tracks.
+///
+/// A recording represents distinct audio that has been used to produce at least one released
+/// track through copying or mastering. A recording itself is never produced solely through
+/// copying or mastering.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Recording)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Recording {
+    /// The title of the recording.
+    pub title: String,
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    /// The length of the recording in milliseconds, if known.
+    pub length: Option<u64>,
+    /// Whether this is a video recording.
+    #[serde(default)]
+    pub video: bool,
+    /// The date of the earliest release this recording appears on.
+    pub first_release_date: Option<PartialDate>,
+    #[serde(default)]
+    pub disambiguation: String,
+    /// [International Standard Recording Codes](https://musicbrainz.org/doc/ISRC), see
+    /// [`RecordingInc::Isrcs`].
+    pub isrcs: Option<Vec<String>>,
+    /// See [`RecordingInc::ArtistCredits`].
+    pub artist_credit: Option<ArtistCredit>,
+    /// The releases this recording appears on, see [`RecordingInc::Releases`].
+    pub releases: Option<Vec<Release>>,
+    /// Links to other entities and URLs, see the `*Rels` variants of [`RecordingInc`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The entities [`Recording`]s can be browsed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingBrowseBy {
+    Artist(Mbid),
+    Collection(Mbid),
+    Release(Mbid),
+    Work(Mbid),
+}
+
+impl BrowseFilter for RecordingBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match self {
+            RecordingBrowseBy::Artist(mbid) => ("artist", mbid),
+            RecordingBrowseBy::Collection(mbid) => ("collection", mbid),
+            RecordingBrowseBy::Release(mbid) => ("release", mbid),
+            RecordingBrowseBy::Work(mbid) => ("work", mbid),
+        }
+    }
+}
+
+/// The subqueries that can be included in a [`Recording`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum RecordingInc {
+    /// The artists credited for the recording.
+    Artists,
+    /// The releases the recording appears on.
+    Releases,
+    /// The ISRCs of the recording.
+    Isrcs,
+    ArtistCredits,
+    Aliases,
+    Annotation,
+    Tags,
+    Genres,
+    Ratings,
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
+}
+
+impl Include for RecordingInc {}
+
+impl Entity for Recording {
+    const NAME: &'static str = "recording";
+    const PLURAL: &'static str = "recordings";
+    type BrowseBy = RecordingBrowseBy;
+    type Include = RecordingInc;
+}
+
+impl Indexed for Recording {
+    const DEFAULT_FIELD: &'static str = "recording";
+
+    fn mbid(&self) -> &Mbid {
+        &self.id
+    }
+
+    fn index(&self, doc: &mut Document) {
+        doc.text("recording", &self.title);
+        doc.artist_credit(self.artist_credit.as_ref());
+        for isrc in self.isrcs.iter().flatten() {
+            doc.keyword("isrc", isrc);
+        }
+        doc.keyword("rid", &self.id.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::mock;
+
+    #[tokio::test]
+    async fn lookup_recording() {
+        let server = MockServer::start().await;
+        mock::mount_lookup(
+            &server,
+            "recording/f7ac10ee-ba8b-4c85-b7c3-97ec8a4b3b1a",
+            "releases isrcs artist-credits",
+            r#"{
+                "id": "f7ac10ee-ba8b-4c85-b7c3-97ec8a4b3b1a",
+                "title": "Schism",
+                "length": 407000,
+                "video": false,
+                "disambiguation": "",
+                "first-release-date": "2001-05-15",
+                "isrcs": ["USVR90100059"],
+                "artist-credit": [{
+                    "name": "Tool",
+                    "joinphrase": "",
+                    "artist": {
+                        "id": "66fc5bf8-daa4-4241-b378-9bc9077939d2",
+                        "name": "Tool",
+                        "sort-name": "Tool",
+                        "type": "Group",
+                        "type-id": "e431f5f6-b5d2-343d-8b36-72607fffb74b",
+                        "disambiguation": "US rock band"
+                    }
+                }],
+                "releases": [{
+                    "id": "2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                    "title": "Lateralus",
+                    "status": "Official",
+                    "status-id": "4e304316-386d-3409-af2e-78857eec5cfe",
+                    "quality": "normal",
+                    "packaging": null,
+                    "packaging-id": null,
+                    "disambiguation": "",
+                    "date": "2001-05-15",
+                    "country": "US",
+                    "barcode": "614223116022",
+                    "text-representation": {"language": "eng", "script": "Latn"}
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let recording: Recording = client
+            .lookup_with(
+                &"f7ac10ee-ba8b-4c85-b7c3-97ec8a4b3b1a".parse().unwrap(),
+                &[
+                    RecordingInc::Releases,
+                    RecordingInc::Isrcs,
+                    RecordingInc::ArtistCredits,
+                ],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(recording.length, Some(407000));
+        assert_eq!(recording.isrcs.unwrap(), ["USVR90100059"]);
+        assert_eq!(recording.artist_credit.unwrap().0[0].artist.name, "Tool");
+        assert_eq!(recording.releases.unwrap()[0].title, "Lateralus");
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+
+use serde::{de::Error as _, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::{
+    Area, Artist, Event, Genre, Instrument, Label, Mbid, PartialDate, Place, Recording, Release,
+    ReleaseGroup, Series, Url, Work,
+};
+
+/// Relationships are a way to represent all the different ways in which entities are connected to
+/// each other and to URLs outside MusicBrainz, e.g. the composer of a work, or the recordings of
+/// a performance of it.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Relationships)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(remote = "Self", rename_all = "kebab-case")]
+pub struct Relationship {
+    /// The type of relationship, e.g. "composer" or "performance".
+    #[serde(rename = "type")]
+    pub r_type: String,
+    pub type_id: Mbid,
+    /// Whether the entity this relationship belongs to is its source or its target.
+    pub direction: Direction,
+    /// Attributes further describing the relationship, e.g. "live" or "guitar".
+    #[serde(default)]
+    pub attributes: Vec<String>,
+    /// Values of the attributes which have one, e.g. the number of an entity in a series.
+    #[serde(default)]
+    pub attribute_values: BTreeMap<String, String>,
+    /// The type IDs of the attributes.
+    #[serde(default)]
+    pub attribute_ids: BTreeMap<String, Mbid>,
+    pub begin: Option<PartialDate>,
+    pub end: Option<PartialDate>,
+    #[serde(default)]
+    pub ended: bool,
+    /// The name the source entity is credited as in this relationship, if it differs from its
+    /// name.
+    #[serde(default)]
+    pub source_credit: String,
+    /// The name the target entity is credited as in this relationship, if it differs from its
+    /// name.
+    #[serde(default)]
+    pub target_credit: String,
+    /// The position of the target in an ordered list, e.g. the parts of a series.
+    pub ordering_key: Option<u32>,
+    /// The kind of entity on the other end of the relationship, e.g. `recording`.
+    pub target_type: String,
+    /// The entity on the other end of the relationship, if MusicBrainz included it.
+    #[serde(flatten, skip_deserializing)]
+    pub target: Option<RelationTarget>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// The target is stored under a key named after `target_type`, so it can only be deserialized once
+// that is known.
+impl<'de> Deserialize<'de> for Relationship {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut fields = Map::deserialize(deserializer)?;
+        let target_type = match fields.get("target-type") {
+            Some(Value::String(target_type)) => target_type.clone(),
+            _ => return Err(D::Error::missing_field("target-type")),
+        };
+        let target = fields
+            .remove(&target_type)
+            .map(|value| RelationTarget::from_value(target_type, value))
+            .transpose()
+            .map_err(D::Error::custom)?;
+        let relationship =
+            Relationship::deserialize(Value::Object(fields)).map_err(D::Error::custom)?;
+        Ok(Self {
+            target,
+            ..relationship
+        })
+    }
+}
+
+impl Serialize for Relationship {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Relationship::serialize(self, serializer)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+/// The entity on the other end of a [`Relationship`].
+///
+/// Entities are boxed, as they vary wildly in size.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RelationTarget {
+    Area(Box<Area>),
+    Artist(Box<Artist>),
+    Event(Box<Event>),
+    Genre(Box<Genre>),
+    Instrument(Box<Instrument>),
+    Label(Box<Label>),
+    Place(Box<Place>),
+    Recording(Box<Recording>),
+    Release(Box<Release>),
+    ReleaseGroup(Box<ReleaseGroup>),
+    Series(Box<Series>),
+    Url(Box<Url>),
+    Work(Box<Work>),
+    /// An entity of a kind this crate doesn't know about yet, kept as it was returned.
+    Unknown {
+        target_type: String,
+        value: Value,
+    },
+}
+
+impl RelationTarget {
+    /// The [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier) of the target entity, unless
+    /// it is of an unknown kind.
+    pub fn id(&self) -> Option<&Mbid> {
+        Some(match self {
+            RelationTarget::Area(area) => &area.id,
+            RelationTarget::Artist(artist) => &artist.id,
+            RelationTarget::Event(event) => &event.id,
+            RelationTarget::Genre(genre) => &genre.id,
+            RelationTarget::Instrument(instrument) => &instrument.id,
+            RelationTarget::Label(label) => &label.id,
+            RelationTarget::Place(place) => &place.id,
+            RelationTarget::Recording(recording) => &recording.id,
+            RelationTarget::Release(release) => &release.id,
+            RelationTarget::ReleaseGroup(release_group) => &release_group.id,
+            RelationTarget::Series(series) => &series.id,
+            RelationTarget::Url(url) => &url.id,
+            RelationTarget::Work(work) => &work.id,
+            RelationTarget::Unknown { .. } => return None,
+        })
+    }
+
+    /// The kind of the target entity, as in [`Relationship::target_type`].
+    pub fn target_type(&self) -> &str {
+        match self {
+            RelationTarget::Area(_) => "area",
+            RelationTarget::Artist(_) => "artist",
+            RelationTarget::Event(_) => "event",
+            RelationTarget::Genre(_) => "genre",
+            RelationTarget::Instrument(_) => "instrument",
+            RelationTarget::Label(_) => "label",
+            RelationTarget::Place(_) => "place",
+            RelationTarget::Recording(_) => "recording",
+            RelationTarget::Release(_) => "release",
+            RelationTarget::ReleaseGroup(_) => "release_group",
+            RelationTarget::Series(_) => "series",
+            RelationTarget::Url(_) => "url",
+            RelationTarget::Work(_) => "work",
+            RelationTarget::Unknown { target_type, .. } => target_type,
+        }
+    }
+
+    /// Deserializes the entity stored under the `target_type` key of a relationship.
+    fn from_value(target_type: String, value: Value) -> Result<Self, serde_json::Error> {
+        fn boxed<T: for<'de> Deserialize<'de>>(value: Value) -> Result<Box<T>, serde_json::Error> {
+            serde_json::from_value(value).map(Box::new)
+        }
+
+        Ok(match target_type.as_str() {
+            "area" => RelationTarget::Area(boxed(value)?),
+            "artist" => RelationTarget::Artist(boxed(value)?),
+            "event" => RelationTarget::Event(boxed(value)?),
+            "genre" => RelationTarget::Genre(boxed(value)?),
+            "instrument" => RelationTarget::Instrument(boxed(value)?),
+            "label" => RelationTarget::Label(boxed(value)?),
+            "place" => RelationTarget::Place(boxed(value)?),
+            "recording" => RelationTarget::Recording(boxed(value)?),
+            "release" => RelationTarget::Release(boxed(value)?),
+            "release_group" => RelationTarget::ReleaseGroup(boxed(value)?),
+            "series" => RelationTarget::Series(boxed(value)?),
+            "url" => RelationTarget::Url(boxed(value)?),
+            "work" => RelationTarget::Work(boxed(value)?),
+            _ => RelationTarget::Unknown { target_type, value },
+        })
+    }
+}
+
+// Flattened into the relationship, under a key named after the kind of the target.
+impl Serialize for RelationTarget {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        match self {
+            RelationTarget::Area(area) => map.serialize_entry("area", area)?,
+            RelationTarget::Artist(artist) => map.serialize_entry("artist", artist)?,
+            RelationTarget::Event(event) => map.serialize_entry("event", event)?,
+            RelationTarget::Genre(genre) => map.serialize_entry("genre", genre)?,
+            RelationTarget::Instrument(instrument) => {
+                map.serialize_entry("instrument", instrument)?
+            }
+            RelationTarget::Label(label) => map.serialize_entry("label", label)?,
+            RelationTarget::Place(place) => map.serialize_entry("place", place)?,
+            RelationTarget::Recording(recording) => map.serialize_entry("recording", recording)?,
+            RelationTarget::Release(release) => map.serialize_entry("release", release)?,
+            RelationTarget::ReleaseGroup(release_group) => {
+                map.serialize_entry("release_group", release_group)?
+            }
+            RelationTarget::Series(series) => map.serialize_entry("series", series)?,
+            RelationTarget::Url(url) => map.serialize_entry("url", url)?,
+            RelationTarget::Work(work) => map.serialize_entry("work", work)?,
+            RelationTarget::Unknown { target_type, value } => {
+                map.serialize_entry(target_type, value)?
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RELATIONSHIP: &str = r#"{
+        "type": "member of band",
+        "type-id": "5be4c609-9afa-4ea0-910b-12ffb71e3821",
+        "direction": "backward",
+        "attributes": ["lead vocals"],
+        "target-type": "artist",
+        "artist": {
+            "id": "bde2d0a4-8c2b-4f14-9a6c-7b1b8f5b2a31",
+            "name": "Maynard James Keenan",
+            "sort-name": "Keenan, Maynard James"
+        }
+    }"#;
+
+    #[test]
+    fn round_trips() {
+        let relationship: Relationship = serde_json::from_str(RELATIONSHIP).unwrap();
+        assert_eq!(
+            relationship.target.as_ref().unwrap().target_type(),
+            "artist"
+        );
+        let json = serde_json::to_string(&relationship).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Relationship>(&json).unwrap(),
+            relationship
+        );
+    }
+
+    #[test]
+    fn propagates_target_errors() {
+        let json = RELATIONSHIP.replace(r#""sort-name": "Keenan, Maynard James""#, r#""x": 1"#);
+        let err = serde_json::from_str::<Relationship>(&json).unwrap_err();
+        assert!(err.to_string().contains("sort-name"), "{}", err);
+    }
+}
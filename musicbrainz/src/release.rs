@@ -1,105 +1,390 @@
-use serde::{Serialize, Deserialize};
-use crate::{Area, Mbid, Entity};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+use crate::{
+    index::{Document, Indexed},
+    medium::Medium,
+    vocabulary::vocabulary,
+    Area, ArtistCredit, BrowseFilter, Entity, Include, Label, Mbid, PartialDate, Relationship,
+    ReleaseGroup,
+};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub struct Release {
     pub title: String,
     pub release_events: Option<Vec<ReleaseEvent>>,
     pub status: Option<ReleaseStatus>,
-    pub cover_art_archive: CoverArtArchive,
+    /// Which artwork the Cover Art Archive has for the release. Missing from search results and
+    /// from releases nested in other entities.
+    pub cover_art_archive: Option<CoverArtArchive>,
     pub packaging: Option<ReleasePackaging>,
     pub asin: Option<String>,
-    pub quality: ReleaseQuality,
+    /// How complete and correct the release data is. Missing from search results.
+    pub quality: Option<ReleaseQuality>,
     pub barcode: Option<String>,
     pub country: Option<String>,
+    /// The date of the earliest release event.
+    pub date: Option<PartialDate>,
+    #[serde(default)]
     pub disambiguation: String,
     pub packaging_id: Option<Mbid>,
     pub id: Mbid,
     pub status_id: Option<Mbid>,
     pub text_representation: ReleaseTextRepresentation,
+    /// The artists the release is credited to, see [`ReleaseInc::ArtistCredits`].
+    pub artist_credit: Option<ArtistCredit>,
+    /// The labels the release was issued by, see [`ReleaseInc::Labels`].
+    pub label_info: Option<Vec<LabelInfo>>,
+    /// The release group this release belongs to, see [`ReleaseInc::ReleaseGroups`].
+    pub release_group: Option<ReleaseGroup>,
+    /// The media of the release, see [`ReleaseInc::Media`] and [`ReleaseInc::Recordings`].
+    pub media: Option<Vec<Medium>>,
+    /// Links to other entities and URLs, see the `*Rels` variants of [`ReleaseInc`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A label a release was issued by, and the catalog number it was issued under.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct LabelInfo {
+    /// The number assigned to the release by the label, often printed on its spine.
+    pub catalog_number: Option<String>,
+    /// The label, if known; releases are sometimes entered with just a catalog number.
+    pub label: Option<Label>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub struct CoverArtArchive {
     pub front: bool,
     pub artwork: bool,
     pub count: u64,
     pub darkened: bool,
     pub back: bool,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub struct ReleaseTextRepresentation {
     pub language: Option<String>,
     pub script: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub struct ReleaseEvent {
     pub area: Option<Area>,
-    // #[serde(with = "time::serde::iso8601")]
-    // date: time::Date,
-    pub date: String,
+    /// The date of the release in this area, which may be only partially known.
+    #[serde(default)]
+    pub date: PartialDate,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+vocabulary! {
+    pub enum ReleaseStatus {
+        Official = "Official",
+        Promotion = "Promotion",
+        Bootleg = "Bootleg",
+        PseudoRelease = "Pseudo-Release",
+        Withdrawn = "Withdrawn",
+        Cancelled = "Cancelled",
+    }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub enum ReleaseStatus {
-    Official,
-    Promotion,
-    Bootleg,
-    #[serde(rename = "Pseudo-Release")]
-    PseudoRelease,
-    Withdrawn,
-    Cancelled,
+vocabulary! {
+    pub enum ReleasePackaging {
+        Book = "Book",
+        Box = "Box",
+        CardboardPaperSleeve = "Cardboard/Paper Sleeve",
+        CassetteCase = "Cassette Case",
+        ClamshellCase = "Clamshell Case",
+        Digibook = "Digibook",
+        Digipak = "Digipak",
+        DiscboxSlider = "Discbox Slider",
+        Fatbox = "Fatbox",
+        GatefoldCover = "Gatefold Cover",
+        JewelCase = "Jewel Case",
+        KeepCase = "Keep Case",
+        Longbox = "Longbox",
+        MetalTin = "Metal Tin",
+        PlasticSleeve = "Plastic Sleeve",
+        Slidepack = "Slidepack",
+        SlimJewelCase = "Slim Jewel Case",
+        SnapCase = "Snap Case",
+        SnapPack = "SnapPack",
+        SuperJewelBox = "Super Jewel Box",
+        Other = "Other",
+        None = "None",
+    }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub enum ReleasePackaging {
-    Book,
-    Box,
-    #[serde(rename = "Cardboard/Paper Sleeve")]
-    CardboardPaperSleeve,
-    #[serde(rename = "Cardboard/Paper Sleeve")]
-    CassetteCase,
-    #[serde(rename = "Clamshell Case")]
-    ClamshellCase,
-    Digibook,
-    Digipak,
-    #[serde(rename = "Discbox Slider")]
-    DiscboxSlider,
-    Fatbox,
-    #[serde(rename = "Gatefold Cover")]
-    GatefoldCover,
-    #[serde(rename = "Jewel Case")]
-    JewelCase,
-    #[serde(rename = "Keep Case")]
-    KeepCase,
-    Longbox,
-    #[serde(rename = "Metal Tin")]
-    MetalTin,
-    #[serde(rename = "Plastic Sleeve")]
-    PlasticSleeve,
-    Slidepack,
-    #[serde(rename = "Slim Jewel Case")]
-    SlimJewelCase,
-    #[serde(rename = "Snap Case")]
-    SnapCase,
-    SnapPack,
-    #[serde(rename = "Super Jewel Box")]
-    SuperJewelBox,
-    Other,
-    None,
+vocabulary! {
+    pub enum ReleaseQuality {
+        High = "high",
+        Normal = "normal",
+        Low = "low",
+    }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ReleaseQuality {
-    High,
-    Normal,
-    Low,
+/// The entities [`Release`]s can be browsed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseBrowseBy {
+    Area(Mbid),
+    Artist(Mbid),
+    Collection(Mbid),
+    Label(Mbid),
+    Recording(Mbid),
+    ReleaseGroup(Mbid),
+    Track(Mbid),
+    /// Releases with a track credited to the artist, even if the release itself is not.
+    TrackArtist(Mbid),
 }
 
-impl Entity for Release{
+impl BrowseFilter for ReleaseBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match self {
+            ReleaseBrowseBy::Area(mbid) => ("area", mbid),
+            ReleaseBrowseBy::Artist(mbid) => ("artist", mbid),
+            ReleaseBrowseBy::Collection(mbid) => ("collection", mbid),
+            ReleaseBrowseBy::Label(mbid) => ("label", mbid),
+            ReleaseBrowseBy::Recording(mbid) => ("recording", mbid),
+            ReleaseBrowseBy::ReleaseGroup(mbid) => ("release-group", mbid),
+            ReleaseBrowseBy::Track(mbid) => ("track", mbid),
+            ReleaseBrowseBy::TrackArtist(mbid) => ("track_artist", mbid),
+        }
+    }
+}
+
+/// The subqueries that can be included in a [`Release`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ReleaseInc {
+    /// The artists credited for the release.
+    Artists,
+    Collections,
+    /// The labels which released the release, and their catalog numbers.
+    Labels,
+    /// The tracklist of every medium, along with each track's recording.
+    Recordings,
+    /// The release group the release belongs to.
+    ReleaseGroups,
+    /// The media of the release, without their tracklists.
+    Media,
+    /// The artist credits of the release and, with `Recordings`, of its tracks.
+    ArtistCredits,
+    /// The disc IDs of every medium, requires `Media` or `Recordings`.
+    #[strum(serialize = "discids")]
+    DiscIds,
+    /// The ISRCs of every recording, requires `Recordings`.
+    Isrcs,
+    Annotation,
+    Aliases,
+    Tags,
+    Genres,
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
+    /// Relationships of the recordings of every track, requires `Recordings`.
+    RecordingLevelRels,
+    /// Relationships of the works of every recording, requires `Recordings`,
+    /// `RecordingLevelRels` and `WorkRels`.
+    WorkLevelRels,
+}
+
+impl Include for ReleaseInc {}
+
+impl Entity for Release {
     const NAME: &'static str = "release";
+    const PLURAL: &'static str = "releases";
+    type BrowseBy = ReleaseBrowseBy;
+    type Include = ReleaseInc;
+}
+
+impl Indexed for Release {
+    const DEFAULT_FIELD: &'static str = "release";
+
+    fn mbid(&self) -> &Mbid {
+        &self.id
+    }
+
+    fn index(&self, doc: &mut Document) {
+        doc.text("release", &self.title);
+        doc.artist_credit(self.artist_credit.as_ref());
+        doc.keyword("barcode", self.barcode.as_deref().unwrap_or_default());
+        for info in self.label_info.iter().flatten() {
+            doc.keyword("catno", info.catalog_number.as_deref().unwrap_or_default());
+            if let Some(label) = &info.label {
+                doc.text("label", &label.name);
+            }
+        }
+        doc.keyword("country", self.country.as_deref().unwrap_or_default());
+        if let Some(status) = &self.status {
+            doc.keyword("status", status.as_str());
+        }
+        if let Some(date) = &self.date {
+            doc.keyword("date", &date.to_string());
+        }
+        doc.keyword("reid", &self.id.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::mock;
+
+    #[tokio::test]
+    async fn lookup_release_tracklist() {
+        let server = MockServer::start().await;
+        mock::mount_lookup(
+            &server,
+            "release/2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+            "recordings discids",
+            r#"{
+                "id": "2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                "title": "Lateralus",
+                "status": "Official",
+                "quality": "normal",
+                "disambiguation": "",
+                "text-representation": {"language": "eng", "script": "Latn"},
+                "media": [{
+                    "position": 1,
+                    "format": "CD",
+                    "format-id": "9712d52a-4509-3d4b-a1a2-67c88c643e31",
+                    "title": "",
+                    "track-count": 2,
+                    "track-offset": 0,
+                    "discs": [{
+                        "id": "2Hx3JC8Me53y3JMmT2pnHVVkS5Y-",
+                        "sectors": 354895,
+                        "offset-count": 2,
+                        "offsets": [150, 38853]
+                    }],
+                    "tracks": [{
+                        "id": "1a1c5e5c-3f5b-3c1c-8b3f-d7aa5e4b5c01",
+                        "number": "1",
+                        "position": 1,
+                        "title": "The Grudge",
+                        "length": 516000,
+                        "recording": {
+                            "id": "5b4f6d8c-5bb2-4f1a-8b1b-6a1e4e6a6f01",
+                            "title": "The Grudge",
+                            "length": 516000,
+                            "video": false,
+                            "disambiguation": ""
+                        }
+                    }, {
+                        "id": "1a1c5e5c-3f5b-3c1c-8b3f-d7aa5e4b5c02",
+                        "number": "2",
+                        "position": 2,
+                        "title": "Eon Blue Apocalypse",
+                        "length": 64000,
+                        "recording": {
+                            "id": "5b4f6d8c-5bb2-4f1a-8b1b-6a1e4e6a6f02",
+                            "title": "Eon Blue Apocalypse",
+                            "length": 64000,
+                            "video": false,
+                            "disambiguation": ""
+                        }
+                    }]
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let release: Release = client
+            .lookup_with(
+                &"2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17".parse().unwrap(),
+                &[ReleaseInc::Recordings, ReleaseInc::DiscIds],
+            )
+            .await
+            .unwrap();
+
+        let media = release.media.unwrap();
+        assert_eq!(media[0].format.as_deref(), Some("CD"));
+        assert_eq!(media[0].discs.as_ref().unwrap()[0].offsets, [150, 38853]);
+        let tracks = media[0].tracks.as_ref().unwrap();
+        assert_eq!(tracks.len(), 2);
+        assert_eq!(tracks[1].number, "2");
+        assert_eq!(
+            tracks[1].recording.as_ref().unwrap().title,
+            "Eon Blue Apocalypse"
+        );
+    }
+
+    #[tokio::test]
+    async fn lookup_release_labels() {
+        let server = MockServer::start().await;
+        mock::mount_lookup(
+            &server,
+            "release/2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+            "labels",
+            r#"{
+                "id": "2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                "title": "Lateralus",
+                "status": "Official",
+                "quality": "normal",
+                "disambiguation": "",
+                "text-representation": {"language": "eng", "script": "Latn"},
+                "label-info": [{
+                    "catalog-number": "61422-31160-2",
+                    "label": {
+                        "id": "4bf8c1b8-0a5a-4e1b-9a3e-6c0fa5cc2d5a",
+                        "name": "Volcano Entertainment",
+                        "sort-name": "Volcano Entertainment",
+                        "type": "Original Production",
+                        "type-id": "7aaa37fe-2def-3476-b359-80245850062d",
+                        "label-code": 6098,
+                        "disambiguation": ""
+                    }
+                }, {
+                    "catalog-number": null,
+                    "label": null
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let release: Release = client
+            .lookup_with(
+                &"2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17".parse().unwrap(),
+                &[ReleaseInc::Labels],
+            )
+            .await
+            .unwrap();
+
+        let label_info = release.label_info.unwrap();
+        assert_eq!(label_info.len(), 2);
+        assert_eq!(
+            label_info[0].catalog_number.as_deref(),
+            Some("61422-31160-2")
+        );
+        let label = label_info[0].label.as_ref().unwrap();
+        assert_eq!(label.name, "Volcano Entertainment");
+        assert_eq!(label.label_code, Some(6098));
+        // Either may be unknown
+        assert!(label_info[1].catalog_number.is_none());
+        assert!(label_info[1].label.is_none());
+    }
 }
@@ -0,0 +1,218 @@
+use std::{
+    fmt,
+    future::Future,
+    path::PathBuf,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use reqwest::{header::CONTENT_TYPE, Request, Response, StatusCode};
+use tower::{util::BoxCloneService, BoxError, Service, ServiceExt};
+
+use crate::retry::Permanent;
+
+/// A transport which answers requests with responses recorded in a directory of fixtures, so that
+/// tests can run offline and against the same data every time.
+///
+/// Every fixture is the body of a successful response, stored in a file named after the path and
+/// query of its URL, e.g. `release_2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17_inc=labels.json`. The
+/// server isn't part of the name, so fixtures recorded against `musicbrainz.org` can be replayed
+/// against any base URL.
+///
+/// Requests without a fixture fail with [`NotRecorded`], which isn't retried, unless the replay is
+/// [`recording`](Self::record), in which case they are sent through to the real transport and
+/// successful responses are saved.
+///
+/// ```no_run
+/// use musicbrainz::{replay::Replay, Client};
+///
+/// let client = Client::builder()
+///     .rate_limit(None)
+///     .build_with_transport(Replay::new("assets/fixtures/replay"));
+/// ```
+#[derive(Clone)]
+pub struct Replay {
+    dir: PathBuf,
+    recorder: Option<BoxCloneService<Request, Response, BoxError>>,
+}
+
+/// The error returned for requests which have no fixture, when not recording.
+#[derive(Debug, thiserror::Error)]
+#[error("No fixture recorded for {0}")]
+pub struct NotRecorded(pub reqwest::Url);
+
+impl Replay {
+    /// Replays the fixtures in `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            recorder: None,
+        }
+    }
+
+    /// Sends requests without a fixture through `transport`, recording its successful responses.
+    ///
+    /// The transport is responsible for the `User-Agent` and `Accept: application/json` headers,
+    /// just as with [`ClientBuilder::build_with_transport`](crate::ClientBuilder::build_with_transport).
+    pub fn record<S>(mut self, transport: S) -> Self
+    where
+        S: Service<Request, Response = Response> + Clone + Send + 'static,
+        S::Error: Into<BoxError>,
+        S::Future: Send + 'static,
+    {
+        self.recorder = Some(BoxCloneService::new(transport.map_err(Into::into)));
+        self
+    }
+
+    /// The path of the fixture for `url`.
+    pub fn path(&self, url: &reqwest::Url) -> PathBuf {
+        // Anything before the web service root, like the path of a mirror, isn't part of the key
+        let path = url.path();
+        let path = path
+            .split_once("/ws/2/")
+            .map_or(path, |(_, resource)| resource);
+        let mut key = path.trim_start_matches('/').to_string();
+        if let Some(query) = url.query() {
+            key.push('?');
+            key.push_str(query);
+        }
+        let mut name: String = key
+            .chars()
+            .map(|c| match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '.' | '=' => c,
+                _ => '_',
+            })
+            .collect();
+        name.push_str(".json");
+        self.dir.join(name)
+    }
+}
+
+impl fmt::Debug for Replay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Replay")
+            .field("dir", &self.dir)
+            .field("recording", &self.recorder.is_some())
+            .finish()
+    }
+}
+
+impl Service<Request> for Replay {
+    type Response = Response;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let url = req.url().clone();
+        let path = self.path(&url);
+        let recorder = self.recorder.clone();
+        Box::pin(async move {
+            match tokio::fs::read_to_string(&path).await {
+                Ok(body) => {
+                    tracing::debug!(%url, path = %path.display(), "Replaying fixture");
+                    return Ok(response(body));
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+
+            let recorder = match recorder {
+                Some(recorder) => recorder,
+                None => return Err(Permanent::new(NotRecorded(url)).into()),
+            };
+            let res = recorder.oneshot(req).await?;
+            if res.status() != StatusCode::OK {
+                tracing::warn!(%url, status = %res.status(), "Not recording unsuccessful response");
+                return Ok(res);
+            }
+            let body = res.text().await?;
+            if let Some(dir) = path.parent() {
+                tokio::fs::create_dir_all(dir).await?;
+            }
+            tokio::fs::write(&path, &body).await?;
+            tracing::debug!(%url, path = %path.display(), "Recorded fixture");
+            Ok(response(body))
+        })
+    }
+}
+
+fn response(body: String) -> Response {
+    http::Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/json")
+        .body(body)
+        .expect("valid replayed response")
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::{Client, Genre, Mbid, MusicBrainzError};
+
+    const GENRE: &str = r#"{"id": "f66d7266-eb3d-4ef3-b4d8-b7cd992f918b", "name": "rock"}"#;
+
+    #[test]
+    fn fixture_names() {
+        let replay = Replay::new("fixtures");
+        let url = "https://musicbrainz.org/ws/2/release/2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17\
+                   ?inc=labels+artist-credits"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            replay.path(&url),
+            PathBuf::from(
+                "fixtures/release_2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17_inc=labels_artist-credits.json"
+            )
+        );
+        let url = "http://127.0.0.1:1234/genre/all?limit=10".parse().unwrap();
+        assert_eq!(
+            replay.path(&url),
+            PathBuf::from("fixtures/genre_all_limit=10.json")
+        );
+    }
+
+    #[tokio::test]
+    async fn record_then_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let mbid: Mbid = "f66d7266-eb3d-4ef3-b4d8-b7cd992f918b".parse().unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ws/2/genre/f66d7266-eb3d-4ef3-b4d8-b7cd992f918b"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(GENRE, "application/json"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let recording = Client::builder()
+            .base_url(server.uri().parse().unwrap())
+            .rate_limit(None)
+            .build_with_transport(Replay::new(dir.path()).record(reqwest::Client::new()));
+        let genre: Genre = recording.lookup(&mbid).await.unwrap();
+        assert_eq!(genre.name, "rock");
+        drop(server);
+
+        let replaying = Client::builder()
+            .rate_limit(None)
+            .build_with_transport(Replay::new(dir.path()));
+        let genre: Genre = replaying.lookup(&mbid).await.unwrap();
+        assert_eq!(genre.name, "rock");
+
+        let other = "00000000-0000-0000-0000-000000000000".parse().unwrap();
+        let err = replaying.lookup::<Genre>(&other).await.unwrap_err();
+        assert!(
+            matches!(err, MusicBrainzError::NotRecorded(ref url) if url.path().ends_with("/genre/00000000-0000-0000-0000-000000000000")),
+            "{:?}",
+            err
+        );
+    }
+}
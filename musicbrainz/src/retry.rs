@@ -0,0 +1,234 @@
+use std::{any::Any, future::Future, pin::Pin, time::Duration};
+
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    Request, Response, StatusCode,
+};
+use tower::BoxError;
+
+/// The retry policy used by [`Client`](crate::Client).
+///
+/// Requests are retried when MusicBrainz throttles them (503 or 429), when they time out (408 or
+/// a local timeout) and on transport errors, other than [`Permanent`] ones. Before each retry the policy waits for as long as the
+/// `Retry-After` header asks, or otherwise for an exponentially growing delay with jitter, either
+/// capped at [`max_delay`](Self::max_delay).
+///
+/// Every retry is reported as a `tracing` event on the `musicbrainz::retry` target, carrying the
+/// attempt number, the delay and the reason.
+#[derive(Debug, Clone)]
+pub struct MusicBrainzRetry {
+    remaining: usize,
+    attempt: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl MusicBrainzRetry {
+    /// A policy which retries a request at most `max_retries` times.
+    pub fn new(max_retries: usize) -> Self {
+        Self {
+            remaining: max_retries,
+            attempt: 0,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+
+    /// The delay before the first retry, doubled for every following one. Defaults to 1s.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// The longest the policy waits between retries, even if MusicBrainz asks for longer with
+    /// `Retry-After`. Defaults to 30s.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// The exponential backoff for the current attempt, with up to half of it randomised so that
+    /// concurrent requests don't retry in lockstep.
+    fn backoff(&self) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(self.attempt))
+            .min(self.max_delay);
+        delay / 2 + (delay / 2).mul_f64(fastrand::f64())
+    }
+
+    fn next(&self, delay: Duration, reason: &str) -> Pin<Box<dyn Future<Output = Self> + Send>> {
+        let next = Self {
+            remaining: self.remaining - 1,
+            attempt: self.attempt + 1,
+            ..self.clone()
+        };
+        tracing::info!(
+            target: "musicbrainz::retry",
+            attempt = next.attempt,
+            remaining = next.remaining,
+            delay_ms = delay.as_millis() as u64,
+            reason,
+            "Retrying request"
+        );
+        Box::pin(async move {
+            tokio::time::sleep(delay).await;
+            next
+        })
+    }
+}
+
+/// An error which retrying the request won't fix, e.g. a response which isn't available offline.
+/// Transports and layers wrap such errors in it so that [`MusicBrainzRetry`] gives up at once.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Permanent(pub BoxError);
+
+impl Permanent {
+    /// Marks `err` as permanent.
+    pub fn new(err: impl Into<BoxError>) -> Self {
+        Self(err.into())
+    }
+}
+
+impl Default for MusicBrainzRetry {
+    fn default() -> Self {
+        Self::new(5)
+    }
+}
+
+impl<E: std::fmt::Debug + 'static> tower::retry::Policy<Request, Response, E> for MusicBrainzRetry {
+    type Future = Pin<Box<dyn Future<Output = Self> + Send>>;
+
+    fn retry(&self, _: &Request, result: Result<&Response, &E>) -> Option<Self::Future> {
+        let (delay, reason) = match result {
+            Ok(response) => match response.status() {
+                StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::REQUEST_TIMEOUT => (
+                    retry_after(response.headers())
+                        .map(|delay| delay.min(self.max_delay))
+                        .unwrap_or_else(|| self.backoff()),
+                    response.status().as_str().to_string(),
+                ),
+                _ => return None,
+            },
+            Err(err) if is_permanent(err) => return None,
+            Err(err) => (self.backoff(), format!("{:?}", err)),
+        };
+
+        if self.remaining == 0 {
+            tracing::warn!(
+                target: "musicbrainz::retry",
+                attempts = self.attempt + 1,
+                reason = reason.as_str(),
+                "Giving up on request"
+            );
+            return None;
+        }
+        Some(self.next(delay, &reason))
+    }
+
+    fn clone_request(&self, req: &Request) -> Option<Request> {
+        req.try_clone()
+    }
+}
+
+fn is_permanent(err: &dyn Any) -> bool {
+    matches!(err.downcast_ref::<BoxError>(), Some(err) if err.is::<Permanent>())
+}
+
+/// Parses the `Retry-After` header, which MusicBrainz sends as a number of seconds.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::retry::Policy;
+
+    fn response(status: u16, retry_after: Option<&str>) -> Response {
+        let mut builder = http::Response::builder().status(status);
+        if let Some(retry_after) = retry_after {
+            builder = builder.header(RETRY_AFTER, retry_after);
+        }
+        builder.body("").unwrap().into()
+    }
+
+    fn request() -> Request {
+        Request::new(
+            reqwest::Method::GET,
+            "https://musicbrainz.org/ws/2/area".parse().unwrap(),
+        )
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let mut policy = MusicBrainzRetry::new(100)
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(8));
+        for attempt in 0..40 {
+            policy.attempt = attempt;
+            let cap = Duration::from_secs(2u64.pow(attempt.min(3)));
+            let delay = policy.backoff();
+            assert!(delay >= cap / 2 && delay <= cap, "{:?} {:?}", delay, cap);
+        }
+    }
+
+    #[test]
+    fn retries_statuses() {
+        let policy = MusicBrainzRetry::new(1);
+        for status in [408, 429, 503] {
+            let res = response(status, None);
+            assert!(Policy::<_, _, ()>::retry(&policy, &request(), Ok(&res)).is_some());
+        }
+        for status in [200, 400, 404, 500] {
+            let res = response(status, None);
+            assert!(Policy::<_, _, ()>::retry(&policy, &request(), Ok(&res)).is_none());
+        }
+        assert!(Policy::<_, Response, _>::retry(&policy, &request(), Err(&"timeout")).is_some());
+        let permanent: BoxError = Permanent::new("missing").into();
+        assert!(Policy::<_, Response, _>::retry(&policy, &request(), Err(&permanent)).is_none());
+    }
+
+    #[tokio::test]
+    async fn retry_after_is_capped() {
+        let res = response(503, Some("3600"));
+        let policy = MusicBrainzRetry::new(1).max_delay(Duration::from_millis(10));
+        let retry = Policy::<_, _, ()>::retry(&policy, &request(), Ok(&res)).unwrap();
+        tokio::time::timeout(Duration::from_secs(1), retry)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn stops_when_exhausted() {
+        let res = response(503, Some("0"));
+        let policy = MusicBrainzRetry::new(2);
+        let policy = Policy::<_, _, ()>::retry(&policy, &request(), Ok(&res))
+            .unwrap()
+            .await;
+        let policy = Policy::<_, _, ()>::retry(&policy, &request(), Ok(&res))
+            .unwrap()
+            .await;
+        assert!(Policy::<_, _, ()>::retry(&policy, &request(), Ok(&res)).is_none());
+    }
+
+    #[test]
+    fn parses_retry_after() {
+        assert_eq!(
+            retry_after(response(503, Some("3")).headers()),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(retry_after(response(503, None).headers()), None);
+        assert_eq!(
+            retry_after(response(503, Some("Wed, 21 Oct 2015 07:28:00 GMT")).headers()),
+            None
+        );
+    }
+}
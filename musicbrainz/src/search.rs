@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// An entity returned by a search, along with how well it matched the query.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/MusicBrainz_API/Search)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SearchResult<E> {
+    /// How well the entity matched the query, from 0 to 100.
+    pub score: u8,
+    #[serde(flatten)]
+    pub entity: E,
+}
@@ -0,0 +1,216 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alias::Alias, vocabulary::vocabulary, BrowseFilter, Entity, Include, Mbid, Relationship,
+};
+
+/// A series is a sequence of separate release groups, releases, recordings, works, artists or
+/// events with a common theme. The theme is usually prominent in the branding of the entities in
+/// the series and the individual entities will often have been given a number indicating their
+/// position in the series.
+///
+/// The entities in a series, and their position in it, are given by its relationships.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Series)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Series {
+    /// The name of the series.
+    pub name: String,
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    #[serde(rename = "type")]
+    pub a_type: Option<SeriesType>,
+    pub type_id: Option<Mbid>,
+    #[serde(default)]
+    pub disambiguation: String,
+    /// See [`SeriesInc::Aliases`].
+    pub aliases: Option<Vec<Alias>>,
+    /// Links to other entities and URLs, see the `*Rels` variants of [`SeriesInc`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Series {
+    /// The relationships to the entities in the series, in the order they appear in it. Only
+    /// filled in when looked up with the `*Rels` include for the kind of entity the series holds,
+    /// e.g. [`SeriesInc::ReleaseGroupRels`].
+    pub fn parts(&self) -> Vec<&Relationship> {
+        let mut parts: Vec<_> = self
+            .relations
+            .iter()
+            .flatten()
+            .filter(|relationship| relationship.ordering_key.is_some())
+            .collect();
+        parts.sort_by_key(|relationship| relationship.ordering_key);
+        parts
+    }
+}
+
+vocabulary! {
+    /// The type of a series, which determines what kind of entities it can contain.
+    pub enum SeriesType {
+        ReleaseGroupSeries = "Release group series",
+        ReleaseSeries = "Release series",
+        RecordingSeries = "Recording series",
+        WorkSeries = "Work series",
+        /// A catalogue of an artist's works, e.g. the Köchel catalogue.
+        Catalogue = "Catalogue",
+        ArtistSeries = "Artist series",
+        ArtistAward = "Artist award",
+        EventSeries = "Event series",
+        /// A series of concerts by the same artist in different places.
+        Tour = "Tour",
+        /// A recurring festival, with each edition being an event.
+        Festival = "Festival",
+        /// A series of performances of the same show at the same place.
+        Run = "Run",
+        AwardCeremony = "Award ceremony",
+        Podcast = "Podcast",
+    }
+}
+
+/// The entities [`Series`] can be browsed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeriesBrowseBy {
+    Collection(Mbid),
+}
+
+impl BrowseFilter for SeriesBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match self {
+            SeriesBrowseBy::Collection(mbid) => ("collection", mbid),
+        }
+    }
+}
+
+/// The subqueries that can be included in a [`Series`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum SeriesInc {
+    Aliases,
+    Annotation,
+    Tags,
+    Genres,
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
+}
+
+impl Include for SeriesInc {}
+
+impl Entity for Series {
+    const NAME: &'static str = "series";
+    const PLURAL: &'static str = "series";
+    type BrowseBy = SeriesBrowseBy;
+    type Include = SeriesInc;
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::{mock, relationship::RelationTarget};
+
+    #[tokio::test]
+    async fn lookup_series_parts() {
+        let server = MockServer::start().await;
+        mock::mount_lookup(
+            &server,
+            "series/d7a9b4a6-5c0f-4b1e-8f2a-3c6d9e1f2a4b",
+            "release-group-rels",
+            r#"{
+                "id": "d7a9b4a6-5c0f-4b1e-8f2a-3c6d9e1f2a4b",
+                "name": "Tool studio albums",
+                "type": "Release group series",
+                "type-id": "4c1c4949-7b6c-3a2d-9d54-a50a27e4fa77",
+                "disambiguation": "",
+                "relations": [{
+                    "type": "part of",
+                    "type-id": "01018437-91d8-36b9-bf89-3f885d53b5bd",
+                    "direction": "backward",
+                    "target-type": "release_group",
+                    "attributes": ["number"],
+                    "attribute-values": {"number": "3"},
+                    "ordering-key": 3,
+                    "release_group": {
+                        "id": "7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93",
+                        "title": "Lateralus",
+                        "disambiguation": ""
+                    }
+                }, {
+                    "type": "part of",
+                    "type-id": "01018437-91d8-36b9-bf89-3f885d53b5bd",
+                    "direction": "backward",
+                    "target-type": "release_group",
+                    "attributes": ["number"],
+                    "attribute-values": {"number": "1"},
+                    "ordering-key": 1,
+                    "release_group": {
+                        "id": "3e6ae3c2-0b8b-3c7b-a4b2-5b2f1f1f8a01",
+                        "title": "Undertow",
+                        "disambiguation": ""
+                    }
+                }, {
+                    "type": "wikidata",
+                    "type-id": "a1eecd98-f2f2-420b-ba8e-e5bc61697869",
+                    "direction": "forward",
+                    "target-type": "url",
+                    "url": {
+                        "id": "0e4b8d4e-8a8e-4f64-a7d5-5e9f7a3c2b1d",
+                        "resource": "https://www.wikidata.org/wiki/Q184225"
+                    }
+                }, {
+                    "type": "part of",
+                    "type-id": "01018437-91d8-36b9-bf89-3f885d53b5bd",
+                    "direction": "backward",
+                    "target-type": "release_group",
+                    "attributes": ["number"],
+                    "attribute-values": {"number": "2"},
+                    "ordering-key": 2,
+                    "release_group": {
+                        "id": "3e6ae3c2-0b8b-3c7b-a4b2-5b2f1f1f8a02",
+                        "title": "Ænima",
+                        "disambiguation": ""
+                    }
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let series: Series = client
+            .lookup_with(
+                &"d7a9b4a6-5c0f-4b1e-8f2a-3c6d9e1f2a4b".parse().unwrap(),
+                &[SeriesInc::ReleaseGroupRels],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(series.a_type, Some(SeriesType::ReleaseGroupSeries));
+        // In series order, without the links which aren't parts of it
+        let titles: Vec<_> = series
+            .parts()
+            .into_iter()
+            .map(|part| match &part.target {
+                Some(RelationTarget::ReleaseGroup(release_group)) => release_group.title.as_str(),
+                target => panic!("unexpected relation target {:?}", target),
+            })
+            .collect();
+        assert_eq!(titles, ["Undertow", "Ænima", "Lateralus"]);
+        assert_eq!(series.parts()[1].attribute_values["number"], "2");
+    }
+}
@@ -0,0 +1,295 @@
+use std::{cell::Cell, fmt::Write as _, marker::PhantomData};
+
+use serde::{
+    de::{DeserializeOwned, DeserializeSeed},
+    ser::SerializeMap,
+    Serialize, Serializer,
+};
+use serde_json::{Map, Value};
+
+/// A response which doesn't exactly match the types it is deserialized into, as reported in
+/// strict mode, see [`ClientBuilder::strict`](crate::ClientBuilder::strict).
+///
+/// Paths are written the same way in both variants, e.g. `media[0].tracks[3].title`.
+#[derive(Debug, thiserror::Error)]
+pub enum StrictError {
+    /// A field is missing, has the wrong type or an invalid value.
+    #[error("Failed to deserialize `{path}`")]
+    Mismatch {
+        path: String,
+        #[source]
+        source: serde_json::Error,
+        /// The fields ignored before the mismatch was found.
+        ignored: Vec<String>,
+    },
+    /// The response was deserialized, but some of its fields have no counterpart in our types, and
+    /// were either ignored or only kept in an `extra` map.
+    #[error("Unknown fields: {}", .0.join(", "))]
+    UnknownFields(Vec<String>),
+}
+
+impl StrictError {
+    /// The paths of the fields which were ignored.
+    pub fn ignored(&self) -> &[String] {
+        match self {
+            Self::Mismatch { ignored, .. } | Self::UnknownFields(ignored) => ignored,
+        }
+    }
+}
+
+thread_local! {
+    /// Set while serializing a value back to find its unmodelled fields, see [`serialize_extra`].
+    static STRIP_EXTRA: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets [`STRIP_EXTRA`] until dropped, so that it is reset even if serializing panics.
+struct StripExtra {
+    previous: bool,
+}
+
+impl StripExtra {
+    fn set() -> Self {
+        Self {
+            previous: STRIP_EXTRA.with(|strip| strip.replace(true)),
+        }
+    }
+}
+
+impl Drop for StripExtra {
+    fn drop(&mut self) {
+        STRIP_EXTRA.with(|strip| strip.set(self.previous));
+    }
+}
+
+/// Deserializes `text`, failing on the first mismatch or if any field is ignored or only kept in
+/// an `extra` map.
+pub(crate) fn from_str<T: DeserializeOwned + Serialize>(text: &str) -> Result<T, StrictError> {
+    let (value, mut ignored) = from_str_seed(text, PhantomData::<T>, |_| true)?;
+    let original: Value = serde_json::from_str(text).map_err(|source| StrictError::Mismatch {
+        path: String::new(),
+        source,
+        ignored: Vec::new(),
+    })?;
+    unmodelled(&original, &value, "", &mut ignored);
+    finish(value, ignored)
+}
+
+/// Deserializes `text` with `seed`, failing on the first mismatch. Returns the value along with
+/// the fields which were ignored and for which `report` returns `true`.
+pub(crate) fn from_str_seed<'de, S: DeserializeSeed<'de>>(
+    text: &'de str,
+    seed: S,
+    report: impl Fn(&str) -> bool,
+) -> Result<(S::Value, Vec<String>), StrictError> {
+    let mut json = serde_json::Deserializer::from_str(text);
+    let mut track = serde_path_to_error::Track::new();
+    let mut ignored = Vec::new();
+    let result = {
+        let mut callback = |path: serde_ignored::Path| {
+            let mut display = String::new();
+            write_path(&mut display, &path);
+            if report(&display) {
+                ignored.push(display);
+            }
+        };
+        let de = serde_ignored::Deserializer::new(&mut json, &mut callback);
+        seed.deserialize(serde_path_to_error::Deserializer::new(de, &mut track))
+    };
+
+    match result.and_then(|value| json.end().map(|()| value)) {
+        Err(source) => Err(StrictError::Mismatch {
+            path: track.path().to_string(),
+            source,
+            ignored,
+        }),
+        Ok(value) => Ok((value, ignored)),
+    }
+}
+
+/// Fails if any field was ignored.
+pub(crate) fn finish<T>(value: T, ignored: Vec<String>) -> Result<T, StrictError> {
+    if ignored.is_empty() {
+        Ok(value)
+    } else {
+        Err(StrictError::UnknownFields(ignored))
+    }
+}
+
+/// Adds the fields of `original` which `value` was deserialized from, but only kept in an `extra`
+/// map, to `ignored`.
+///
+/// These are found by serializing `value` back without its `extra` maps, and looking for the
+/// fields of `original` which are missing from the result.
+pub(crate) fn unmodelled<T: Serialize>(
+    original: &Value,
+    value: &T,
+    path: &str,
+    ignored: &mut Vec<String>,
+) {
+    let modelled = {
+        let _strip = StripExtra::set();
+        serde_json::to_value(value)
+    };
+    if let Ok(modelled) = modelled {
+        diff(original, &modelled, &mut path.to_string(), ignored);
+    }
+}
+
+fn diff(original: &Value, modelled: &Value, path: &mut String, ignored: &mut Vec<String>) {
+    let len = path.len();
+    match (original, modelled) {
+        (Value::Object(original), Value::Object(modelled)) => {
+            for (key, value) in original {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+                match modelled.get(key) {
+                    Some(modelled) => diff(value, modelled, path, ignored),
+                    None if !ignored.contains(path) => ignored.push(path.clone()),
+                    None => {}
+                }
+                path.truncate(len);
+            }
+        }
+        (Value::Array(original), Value::Array(modelled)) => {
+            for (i, (value, modelled)) in original.iter().zip(modelled).enumerate() {
+                let _ = write!(path, "[{}]", i);
+                diff(value, modelled, path, ignored);
+                path.truncate(len);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Serializes an `extra` map, flattened into the value it belongs to.
+pub(crate) fn serialize_extra<S: Serializer>(
+    extra: &Map<String, Value>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if STRIP_EXTRA.with(Cell::get) {
+        serializer.serialize_map(Some(0))?.end()
+    } else {
+        extra.serialize(serializer)
+    }
+}
+
+/// Writes a path like `serde_path_to_error` does, skipping the segments for `Option`s and newtypes
+/// which `serde_ignored` includes.
+fn write_path(out: &mut String, path: &serde_ignored::Path) {
+    match path {
+        serde_ignored::Path::Root => {}
+        serde_ignored::Path::Seq { parent, index } => {
+            write_path(out, parent);
+            let _ = write!(out, "[{}]", index);
+        }
+        serde_ignored::Path::Map { parent, key } => {
+            write_path(out, parent);
+            if !out.is_empty() {
+                out.push('.');
+            }
+            out.push_str(key);
+        }
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => write_path(out, parent),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{work::WorkAttribute, Genre, ReleaseGroup};
+
+    #[test]
+    fn accepts_exact_matches() {
+        let genre: Genre = from_str(
+            r#"{"id": "f66d7266-eb3d-4ef3-b4d8-b7cd992f918b", "name": "rock", "disambiguation": ""}"#,
+        )
+        .unwrap();
+        assert_eq!(genre.name, "rock");
+    }
+
+    #[test]
+    fn reports_unknown_fields() {
+        let err = from_str::<Vec<WorkAttribute>>(r#"[{"type": "Key", "value": "C", "new": 1}]"#)
+            .unwrap_err();
+        assert_eq!(err.ignored(), ["[0].new"]);
+
+        // Fields kept in an `extra` map, however deeply nested, are reported too
+        let err = from_str::<Vec<Genre>>(
+            r#"[{"id": "f66d7266-eb3d-4ef3-b4d8-b7cd992f918b", "name": "rock", "new": 1}]"#,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, StrictError::UnknownFields(ref fields) if fields == &["[0].new"]),
+            "{:?}",
+            err
+        );
+        let err = from_str::<ReleaseGroup>(
+            r#"{
+                "id": "7b7e95c5-5a2c-3d55-9dcd-b52f3a6a6d93",
+                "title": "Lateralus",
+                "rating": {"value": 4.5, "votes-count": 20},
+                "releases": [{
+                    "id": "2c76e6b1-6d53-4bc2-99e0-2a5bd6e3fd17",
+                    "title": "Lateralus",
+                    "disambiguation": "",
+                    "text-representation": {"language": "eng", "script": "Latn"},
+                    "tags": []
+                }]
+            }"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.ignored(), ["rating", "releases[0].tags"]);
+    }
+
+    #[test]
+    fn resets_after_panics() {
+        struct Panics;
+
+        impl Serialize for Panics {
+            fn serialize<S: Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+                panic!("failed to serialize");
+            }
+        }
+
+        let result = std::panic::catch_unwind(|| {
+            unmodelled(&Value::Null, &Panics, "", &mut Vec::new());
+        });
+        assert!(result.is_err());
+        assert!(!STRIP_EXTRA.with(Cell::get));
+    }
+
+    #[test]
+    fn reports_mismatch_paths() {
+        #[derive(Debug, serde::Deserialize, Serialize)]
+        struct Attribute {
+            value: String,
+        }
+
+        let err = from_str::<Vec<Attribute>>(
+            r#"[
+                {"value": "C major", "new": null},
+                {"value": 1}
+            ]"#,
+        )
+        .unwrap_err();
+        match err {
+            StrictError::Mismatch { path, ignored, .. } => {
+                assert_eq!(path, "[1].value");
+                assert_eq!(ignored, ["[0].new"]);
+            }
+            err => panic!("{:?}", err),
+        }
+
+        let err =
+            from_str::<Genre>(r#"{"id": "f66d7266-eb3d-4ef3-b4d8-b7cd992f918b"}"#).unwrap_err();
+        assert!(
+            matches!(err, StrictError::Mismatch { ref source, .. } if source.to_string().contains("missing field `name`")),
+            "{:?}",
+            err
+        );
+    }
+}
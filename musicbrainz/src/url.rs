@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{BrowseFilter, Entity, Include, Mbid, Relationship};
+
+/// A URL in MusicBrainz is a specific entity representing a regular internet Uniform Resource
+/// Locator. A URL entity can be linked to other entities through relationships, e.g. to the
+/// Discogs page of a release or the official homepage of an artist.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/URL)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Url {
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    /// The URL itself, e.g. `https://www.wikidata.org/wiki/Q1365`.
+    pub resource: String,
+    /// Links to other entities and URLs, see the `*Rels` variants of [`UrlInc`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// URLs can't be browsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlBrowseBy {}
+
+impl BrowseFilter for UrlBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match *self {}
+    }
+}
+
+/// The subqueries that can be included in a [`Url`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum UrlInc {
+    AreaRels,
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    LabelRels,
+    PlaceRels,
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    WorkRels,
+}
+
+impl Include for UrlInc {}
+
+impl Entity for Url {
+    const NAME: &'static str = "url";
+    const PLURAL: &'static str = "urls";
+    type BrowseBy = UrlBrowseBy;
+    type Include = UrlInc;
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::mock;
+
+    #[tokio::test]
+    async fn lookup_url() {
+        let server = MockServer::start().await;
+        mock::mount_lookup(
+            &server,
+            "url/0e4b8d4e-8a8e-4f64-a7d5-5e9f7a3c2b1d",
+            "artist-rels",
+            r#"{
+                "id": "0e4b8d4e-8a8e-4f64-a7d5-5e9f7a3c2b1d",
+                "resource": "https://www.wikidata.org/wiki/Q184225",
+                "relations": [{
+                    "type": "wikidata",
+                    "type-id": "689870a4-a1e4-4912-b17f-7b2664215698",
+                    "direction": "backward",
+                    "target-type": "artist",
+                    "artist": {
+                        "id": "66fc5bf8-daa4-4241-b378-9bc9077939d2",
+                        "name": "Tool",
+                        "sort-name": "Tool",
+                        "disambiguation": "US rock band"
+                    }
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let url: Url = client
+            .lookup_with(
+                &"0e4b8d4e-8a8e-4f64-a7d5-5e9f7a3c2b1d".parse().unwrap(),
+                &[UrlInc::ArtistRels],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(url.resource, "https://www.wikidata.org/wiki/Q184225");
+        let relations = url.relations.unwrap();
+        assert_eq!(relations[0].target_type, "artist");
+        assert_eq!(
+            relations[0]
+                .target
+                .as_ref()
+                .unwrap()
+                .id()
+                .unwrap()
+                .to_string(),
+            "66fc5bf8-daa4-4241-b378-9bc9077939d2"
+        );
+    }
+}
@@ -0,0 +1,123 @@
+/// Defines an enum for one of the controlled vocabularies MusicBrainz uses, such as the types of
+/// an entity.
+///
+/// MusicBrainz adds new values to these from time to time, so besides the listed variants, each
+/// enum gets an `Unknown(String)` variant which holds any value it doesn't recognise. Values
+/// (de)serialize as their string form, so unknown ones round-trip unchanged.
+///
+/// ```ignore
+/// vocabulary! {
+///     pub enum ReleaseStatus {
+///         Official = "Official",
+///         PseudoRelease = "Pseudo-Release",
+///     }
+/// }
+/// ```
+macro_rules! vocabulary {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value this version of the crate doesn't know about.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The value as used by MusicBrainz.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = ::std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $($value => Self::$variant,)*
+                    _ => Self::Unknown(s.to_string()),
+                })
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let s = <::std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                Ok(match s.parse() {
+                    Ok(value) => value,
+                    Err(infallible) => match infallible {},
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use vocabulary;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        area::AreaType,
+        release::{ReleasePackaging, ReleaseQuality},
+    };
+
+    #[test]
+    fn known_values() {
+        let packaging: Vec<ReleasePackaging> =
+            serde_json::from_str(r#"["Cardboard/Paper Sleeve", "Cassette Case"]"#).unwrap();
+        assert_eq!(
+            packaging,
+            [
+                ReleasePackaging::CardboardPaperSleeve,
+                ReleasePackaging::CassetteCase
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&ReleaseQuality::Normal).unwrap(),
+            r#""normal""#
+        );
+    }
+
+    #[test]
+    fn unknown_values_round_trip() {
+        let area_type: AreaType = serde_json::from_str(r#""Country subdivision""#).unwrap();
+        assert_eq!(
+            area_type,
+            AreaType::Unknown("Country subdivision".to_string())
+        );
+        assert_eq!(area_type.to_string(), "Country subdivision");
+        assert_eq!(
+            serde_json::to_string(&area_type).unwrap(),
+            r#""Country subdivision""#
+        );
+    }
+}
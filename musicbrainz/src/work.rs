@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alias::Alias,
+    index::{Document, Indexed},
+    relationship::Relationship,
+    vocabulary::vocabulary,
+    BrowseFilter, Entity, Include, Mbid,
+};
+
+/// In MusicBrainz terminology, a work is a distinct intellectual or artistic creation, which can
+/// be expressed in the form of one or more audio recordings. While a work in MusicBrainz is
+/// usually musical in nature, it is not necessarily so, e.g. a work could be a novel, play, poem
+/// or essay, later recorded as an oratorio or audiobook.
+///
+/// # See Also
+/// [Upstream documentation.](https://musicbrainz.org/doc/Work)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Work {
+    /// The canonical title of the work, expressed in the language it was originally written.
+    pub title: String,
+    /// [MBID](https://musicbrainz.org/doc/MusicBrainz_Identifier)
+    pub id: Mbid,
+    #[serde(rename = "type")]
+    pub a_type: Option<WorkType>,
+    pub type_id: Option<Mbid>,
+    /// The [ISO 639-3](https://en.wikipedia.org/wiki/ISO_639-3) codes of the languages of the
+    /// lyrics, `zxx` for works with no lyrics.
+    #[serde(default)]
+    pub languages: Vec<String>,
+    /// [International Standard Musical Work Codes](https://musicbrainz.org/doc/ISWC).
+    #[serde(default)]
+    pub iswcs: Vec<String>,
+    /// Additional information about the work, e.g. its key or catalogue numbers.
+    #[serde(default)]
+    pub attributes: Vec<WorkAttribute>,
+    #[serde(default)]
+    pub disambiguation: String,
+    /// See [`WorkInc::Aliases`].
+    pub aliases: Option<Vec<Alias>>,
+    /// Links to recordings of the work, its composers, etc. See [`WorkInc::RecordingRels`].
+    pub relations: Option<Vec<Relationship>>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+vocabulary! {
+    pub enum WorkType {
+        Aria = "Aria",
+        AudioDrama = "Audio drama",
+        Ballet = "Ballet",
+        BeijingOpera = "Beijing opera",
+        Cantata = "Cantata",
+        Concerto = "Concerto",
+        Etude = "Étude",
+        IncidentalMusic = "Incidental music",
+        Madrigal = "Madrigal",
+        Mass = "Mass",
+        Motet = "Motet",
+        Musical = "Musical",
+        Opera = "Opera",
+        Operetta = "Operetta",
+        Oratorio = "Oratorio",
+        Overture = "Overture",
+        Partita = "Partita",
+        Play = "Play",
+        Poem = "Poem",
+        Prose = "Prose",
+        Quartet = "Quartet",
+        Sonata = "Sonata",
+        Song = "Song",
+        SongCycle = "Song-cycle",
+        Soundtrack = "Soundtrack",
+        Suite = "Suite",
+        SymphonicPoem = "Symphonic poem",
+        Symphony = "Symphony",
+        Zarzuela = "Zarzuela",
+    }
+}
+
+/// An attribute of a [`Work`], e.g. `Key: C major`.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct WorkAttribute {
+    /// The kind of attribute, e.g. "Key" or "Köchel catalogue".
+    #[serde(rename = "type")]
+    pub a_type: String,
+    pub type_id: Option<Mbid>,
+    pub value: String,
+    /// Set when `value` is one of a fixed list of allowed values, e.g. for keys.
+    pub value_id: Option<Mbid>,
+    /// The fields MusicBrainz returned which aren't modelled above.
+    #[serde(flatten, serialize_with = "crate::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The entities [`Work`]s can be browsed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkBrowseBy {
+    Artist(Mbid),
+    Collection(Mbid),
+}
+
+impl BrowseFilter for WorkBrowseBy {
+    fn linked(&self) -> (&'static str, &Mbid) {
+        match self {
+            WorkBrowseBy::Artist(mbid) => ("artist", mbid),
+            WorkBrowseBy::Collection(mbid) => ("collection", mbid),
+        }
+    }
+}
+
+/// The subqueries that can be included in a [`Work`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum WorkInc {
+    Aliases,
+    Annotation,
+    Tags,
+    Genres,
+    Ratings,
+    AreaRels,
+    /// Relationships to artists, e.g. composers and lyricists.
+    ArtistRels,
+    EventRels,
+    InstrumentRels,
+    /// Relationships to labels, e.g. publishers.
+    LabelRels,
+    PlaceRels,
+    /// Relationships to recordings, i.e. the recordings of the work.
+    RecordingRels,
+    ReleaseRels,
+    ReleaseGroupRels,
+    SeriesRels,
+    UrlRels,
+    /// Relationships to other works, e.g. the parts of the work.
+    WorkRels,
+}
+
+impl Include for WorkInc {}
+
+impl Entity for Work {
+    const NAME: &'static str = "work";
+    const PLURAL: &'static str = "works";
+    type BrowseBy = WorkBrowseBy;
+    type Include = WorkInc;
+}
+
+impl Indexed for Work {
+    const DEFAULT_FIELD: &'static str = "work";
+
+    fn mbid(&self) -> &Mbid {
+        &self.id
+    }
+
+    fn index(&self, doc: &mut Document) {
+        doc.text("work", &self.title);
+        doc.aliases(self.aliases.as_deref());
+        for iswc in &self.iswcs {
+            doc.keyword("iswc", iswc);
+        }
+        doc.keyword("wid", &self.id.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::{
+        mock,
+        relationship::{Direction, RelationTarget},
+    };
+
+    #[tokio::test]
+    async fn lookup_work_recordings() {
+        let server = MockServer::start().await;
+        mock::mount_lookup(
+            &server,
+            "work/a4ff5b5b-5e48-3a7e-8c49-3f1b1a7c3f34",
+            "recording-rels",
+            r#"{
+                "id": "a4ff5b5b-5e48-3a7e-8c49-3f1b1a7c3f34",
+                "title": "Schism",
+                "type": "Song",
+                "type-id": "f061270a-2fd6-32f1-a641-f0f8676d14e6",
+                "language": "eng",
+                "languages": ["eng"],
+                "iswcs": ["T-070.185.227-6"],
+                "attributes": [{
+                    "type": "Key",
+                    "type-id": "7526c19d-3be4-3420-b6cc-9fb6e49fa1a9",
+                    "value": "E minor",
+                    "value-id": "73ed8a33-4e43-3f5c-a3c2-3e4f8d2de1d0"
+                }],
+                "disambiguation": "",
+                "relations": [{
+                    "type": "performance",
+                    "type-id": "a3005666-a872-32c3-ad06-98af558e99b0",
+                    "direction": "backward",
+                    "target-type": "recording",
+                    "attributes": [],
+                    "attribute-values": {},
+                    "attribute-ids": {},
+                    "begin": null,
+                    "end": null,
+                    "ended": false,
+                    "source-credit": "",
+                    "target-credit": "",
+                    "recording": {
+                        "id": "f7ac10ee-ba8b-4c85-b7c3-97ec8a4b3b1a",
+                        "title": "Schism",
+                        "length": 407000,
+                        "video": false,
+                        "disambiguation": ""
+                    }
+                }]
+            }"#,
+        )
+        .await;
+
+        let client = mock::client(&server);
+        let work: Work = client
+            .lookup_with(
+                &"a4ff5b5b-5e48-3a7e-8c49-3f1b1a7c3f34".parse().unwrap(),
+                &[WorkInc::RecordingRels],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(work.a_type, Some(WorkType::Song));
+        assert_eq!(work.iswcs, ["T-070.185.227-6"]);
+        assert_eq!(work.attributes[0].value, "E minor");
+        let relations = work.relations.unwrap();
+        assert_eq!(relations[0].direction, Direction::Backward);
+        match &relations[0].target {
+            Some(RelationTarget::Recording(recording)) => {
+                assert_eq!(recording.title, "Schism")
+            }
+            target => panic!("unexpected relation target {:?}", target),
+        }
+    }
+}